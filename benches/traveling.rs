@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use surviving_the_trip::core_logic::{
+    setting::{LogicalCoordinates, WorldTileDimensions},
+    traveling::{DEFAULT_MOVEMENT_COST, Graph, Movement, Pathfinding, WalkableTile},
+};
+
+/// The sizes of the worlds being traveled through, starting with the
+/// size of the campsite maps.
+const WORLD_SIZES: [(usize, usize); 3] = [(40, 23), (80, 46), (160, 92)];
+
+/// The traversal graph as it was before being laid out as a grid, where
+/// nodes are found by searching through all of them, and edges are kept
+/// in a map from each node ID.
+struct LinearGraph {
+    world_size: WorldTileDimensions,
+    nodes: Vec<(usize, LogicalCoordinates)>,
+    edges: HashMap<usize, Vec<usize>>,
+}
+
+impl LinearGraph {
+    fn from_tiles(tiles: &[LogicalCoordinates], world_size: &WorldTileDimensions) -> Self {
+        let mut nodes: Vec<(usize, LogicalCoordinates)> = tiles
+            .iter()
+            .map(|tile_location| (tile_location.to_1d(world_size), *tile_location))
+            .collect();
+        nodes.sort_by_key(|(node_id, _)| *node_id);
+        nodes.dedup_by_key(|(node_id, _)| *node_id);
+
+        let mut edges: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (node_id, location) in &nodes {
+            let mut earlier_neighbors = Vec::new();
+            if location.get_x() > 0 {
+                earlier_neighbors.push(node_id - 1);
+            }
+            if location.get_y() > 0 {
+                earlier_neighbors.push(node_id - world_size.get_width());
+            }
+
+            for neighbor_id in earlier_neighbors {
+                if nodes
+                    .binary_search_by(|(other_id, _)| other_id.cmp(&neighbor_id))
+                    .is_ok()
+                {
+                    edges.entry(*node_id).or_default().push(neighbor_id);
+                    edges.entry(neighbor_id).or_default().push(*node_id);
+                }
+            }
+        }
+
+        Self {
+            world_size: *world_size,
+            nodes,
+            edges,
+        }
+    }
+
+    fn get_node_at(&self, position: &LogicalCoordinates) -> &(usize, LogicalCoordinates) {
+        let position_1d = position.to_1d(&self.world_size);
+
+        self.nodes
+            .iter()
+            .find(|(node_id, _)| *node_id == position_1d)
+            .expect("get_node_at: Could not find node")
+    }
+
+    fn get_node_by_id(&self, desired_node_id: usize) -> &(usize, LogicalCoordinates) {
+        self.nodes
+            .iter()
+            .find(|(node_id, _)| *node_id == desired_node_id)
+            .expect("get_node_by_id: Could not find node.")
+    }
+
+    fn get_edges(&self, node_id: usize) -> Vec<&(usize, LogicalCoordinates)> {
+        self.edges[&node_id]
+            .iter()
+            .map(|neighbor_id| self.get_node_by_id(*neighbor_id))
+            .collect()
+    }
+}
+
+/// Returns every tile in a world with nothing in the way.
+fn get_open_world(width: usize, height: usize) -> Vec<LogicalCoordinates> {
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| LogicalCoordinates::new(x, y, 0)))
+        .collect()
+}
+
+/// Returns a Graph of a world where every tile can be walked on.
+fn get_open_graph(tiles: &[LogicalCoordinates], world_size: &WorldTileDimensions) -> Graph {
+    let walkable_tiles: Vec<WalkableTile> = tiles
+        .iter()
+        .map(|tile| WalkableTile::new(*tile, DEFAULT_MOVEMENT_COST))
+        .collect();
+
+    Graph::from_walkable_tiles(&walkable_tiles, world_size)
+}
+
+fn bench_neighbor_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("neighbor_lookups");
+    group.sample_size(10);
+
+    for (width, height) in WORLD_SIZES {
+        let world_size = WorldTileDimensions::new(width, height, 1);
+        let tiles = get_open_world(width, height);
+        let size_name = format!("{}x{}", width, height);
+
+        let linear_graph = LinearGraph::from_tiles(&tiles, &world_size);
+        group.bench_with_input(
+            BenchmarkId::new("linear", &size_name),
+            &tiles,
+            |b, tiles| {
+                b.iter(|| {
+                    tiles
+                        .iter()
+                        .map(|tile| {
+                            let (node_id, _) = linear_graph.get_node_at(tile);
+                            linear_graph.get_edges(*node_id).len()
+                        })
+                        .sum::<usize>()
+                })
+            },
+        );
+
+        let grid_graph = get_open_graph(&tiles, &world_size);
+        group.bench_with_input(BenchmarkId::new("grid", &size_name), &tiles, |b, tiles| {
+            b.iter(|| {
+                tiles
+                    .iter()
+                    .map(|tile| {
+                        let node = grid_graph.get_node_at(tile);
+                        grid_graph.get_edges(node).count()
+                    })
+                    .sum::<usize>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_pathfinding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pathfinding");
+    group.sample_size(10);
+
+    for (width, height) in WORLD_SIZES {
+        let world_size = WorldTileDimensions::new(width, height, 1);
+        let tiles = get_open_world(width, height);
+        let graph = get_open_graph(&tiles, &world_size);
+        let source = LogicalCoordinates::new(0, 0, 0);
+        let destination = LogicalCoordinates::new(width - 1, height - 1, 0);
+        let size_name = format!("{}x{}", width, height);
+
+        group.bench_function(BenchmarkId::new("shortest", &size_name), |b| {
+            b.iter(|| Pathfinding::shortest_path(&source, &destination, &graph))
+        });
+
+        group.bench_function(BenchmarkId::new("cheapest", &size_name), |b| {
+            b.iter(|| Pathfinding::cheapest_path(&source, &destination, &graph, Movement::Straight))
+        });
+
+        group.bench_function(BenchmarkId::new("cheapest_diagonal", &size_name), |b| {
+            b.iter(|| Pathfinding::cheapest_path(&source, &destination, &graph, Movement::Diagonal))
+        });
+
+        group.bench_function(BenchmarkId::new("explore_all", &size_name), |b| {
+            b.iter(|| Pathfinding::explore_all(&source, &graph))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_neighbor_lookups, bench_pathfinding);
+criterion_main!(benches);
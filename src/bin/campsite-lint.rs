@@ -0,0 +1,78 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use surviving_the_trip::core_logic::{
+    linting::lint_campsite,
+    phrasing::Locale,
+    progressing::{ObjectivesDirectory, get_objectives_file_path},
+};
+
+const USAGE: &str =
+    "Usage: campsite-lint [--objectives-dir <directory>] [--locale <language code>] <map.tmx>...";
+
+fn main() -> ExitCode {
+    let mut arguments = std::env::args().skip(1);
+    let mut objectives_directory = None;
+    let mut locale = Locale::default();
+    let mut map_paths = Vec::new();
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--objectives-dir" => {
+                let Some(directory) = arguments.next() else {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                };
+                objectives_directory = Some(PathBuf::from(directory));
+            }
+            "--locale" => {
+                let Some(locale_code) = arguments.next() else {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                };
+                locale = Locale::new(locale_code);
+            }
+            _ if argument.starts_with("--") => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+            _ => map_paths.push(PathBuf::from(argument)),
+        }
+    }
+
+    if map_paths.is_empty() {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let mut num_problems = 0;
+    for map_path in map_paths {
+        // Objectives are found next to their map unless told otherwise,
+        // and are then looked up by the map's name just like the game does.
+        let Some(map_name) = map_path.file_stem() else {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        };
+        let map_objectives_directory = match &objectives_directory {
+            Some(objectives_directory) => ObjectivesDirectory::new(objectives_directory.clone()),
+            None => ObjectivesDirectory::new(map_path.with_file_name("")),
+        };
+        let objectives_path = get_objectives_file_path(
+            &map_objectives_directory,
+            &map_name.to_string_lossy(),
+            &locale,
+        );
+
+        for campsite_problem in lint_campsite(&map_path, &objectives_path) {
+            println!("{}", campsite_problem);
+            num_problems += 1;
+        }
+    }
+
+    if num_problems > 0 {
+        println!("Found {} problem(s).", num_problems);
+        return ExitCode::FAILURE;
+    }
+
+    println!("No problems found.");
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,68 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use surviving_the_trip::{
+    core_logic::clicking::parse_click_script,
+    stream_logic::mock_heat_server::{MockClickFeed, MockHeatServer},
+};
+
+const USAGE: &str = "Usage: mock-heat-server [--address <host:port>] (--script <click script> | --random <seconds between clicks> | --stdin)";
+
+/// Returns the address to listen on and where clicks should come from,
+/// as requested from the command line.
+fn parse_arguments(mut arguments: impl Iterator<Item = String>) -> (String, MockClickFeed) {
+    let mut address = String::from("127.0.0.1:8080");
+    let mut click_feed = None;
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--address" => address = arguments.next().expect(USAGE),
+            "--script" => {
+                let click_script_path = PathBuf::from(arguments.next().expect(USAGE));
+                let click_script = fs::read_to_string(&click_script_path).unwrap_or_else(|_| {
+                    panic!(
+                        "parse_arguments: Could not read click script {}",
+                        click_script_path.display()
+                    )
+                });
+                let scripted_clicks = parse_click_script(&click_script)
+                    .unwrap_or_else(|parse_error| panic!("parse_arguments: {}", parse_error));
+                click_feed = Some(MockClickFeed::Script(scripted_clicks));
+            }
+            "--random" => {
+                // Clicks can't be sent any faster than back to back.
+                let time_between_clicks = arguments
+                    .next()
+                    .and_then(|seconds| seconds.parse::<f32>().ok())
+                    .filter(|seconds| *seconds > 0.0)
+                    .and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+                    .expect(USAGE);
+                click_feed = Some(MockClickFeed::Random(time_between_clicks));
+            }
+            "--stdin" => click_feed = Some(MockClickFeed::Stdin),
+            _ => panic!("{}", USAGE),
+        }
+    }
+
+    (address, click_feed.expect(USAGE))
+}
+
+#[tokio::main]
+async fn main() {
+    let (address, click_feed) = parse_arguments(std::env::args().skip(1));
+
+    let mock_heat_server = MockHeatServer::bind(&address, click_feed)
+        .await
+        .expect("main: Could not listen on the given address.");
+    let mock_heat_url = mock_heat_server
+        .get_url()
+        .expect("main: Could not find the address being listened on.");
+    println!(
+        "main: Listening for games. Run the game with --heat <any channel id> --heat-url {}",
+        mock_heat_url
+    );
+
+    mock_heat_server
+        .run()
+        .await
+        .expect("main: Stopped accepting games.");
+}
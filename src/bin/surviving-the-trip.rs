@@ -1,10 +1,152 @@
+use std::{path::PathBuf, time::Duration};
+
 use bevy::prelude::*;
 
-use surviving_the_trip::stream_logic::StreamLogic;
+use surviving_the_trip::{
+    core_logic::{
+        choosing::{TieBreaker, VotingTime},
+        phrasing::Locale,
+    },
+    stream_logic::{ClickSourceSettings, StreamLogic, networking::HEAT_API_URL},
+};
+
+const USAGE: &str = "Usage: surviving-the-trip [--heat <channel id>] [--heat-url <base url>] [--mouse] [--script <click script>] [--locale <language code>] [--voting-time <seconds>] [--tie-breaker <first-listed|first-voted|random>] [--lobby-time <seconds>] [--min-campers <count>]";
+
+/// Returns the Stream Logic set up as requested from the command line,
+/// using the default Click Sources when none are requested.
+fn parse_arguments(mut arguments: impl Iterator<Item = String>) -> StreamLogic {
+    let mut click_sources = Vec::new();
+    let mut heat_api_url = None;
+    let mut locale = Locale::default();
+    let mut voting_time = None;
+    let mut tie_breaker = None;
+    let mut lobby_wait_time = None;
+    let mut min_campers = None;
+
+    while let Some(argument) = arguments.next() {
+        let click_source = match argument.as_str() {
+            "--heat" => {
+                let channel_id = arguments.next().expect(USAGE);
+                ClickSourceSettings::HeatApi {
+                    url: String::new(),
+                    channel_id,
+                }
+            }
+            "--heat-url" => {
+                heat_api_url = Some(arguments.next().expect(USAGE));
+                continue;
+            }
+            "--locale" => {
+                locale = Locale::new(arguments.next().expect(USAGE));
+                continue;
+            }
+            "--voting-time" => {
+                let voting_seconds = arguments
+                    .next()
+                    .and_then(|voting_seconds| voting_seconds.parse().ok())
+                    .filter(|voting_seconds| *voting_seconds > 0)
+                    .expect(USAGE);
+                voting_time = Some(VotingTime::new(Duration::from_secs(voting_seconds)));
+                continue;
+            }
+            "--tie-breaker" => {
+                let tie_breaker_name = arguments.next().expect(USAGE);
+                tie_breaker = match tie_breaker_name.as_str() {
+                    "first-listed" => Some(TieBreaker::FirstListed),
+                    "first-voted" => Some(TieBreaker::FirstVoted),
+                    "random" => Some(TieBreaker::Random),
+                    _ => panic!("{}", USAGE),
+                };
+                continue;
+            }
+            "--lobby-time" => {
+                let lobby_seconds = arguments
+                    .next()
+                    .and_then(|lobby_seconds| lobby_seconds.parse().ok())
+                    .expect(USAGE);
+                lobby_wait_time = Some(Duration::from_secs(lobby_seconds));
+                continue;
+            }
+            "--min-campers" => {
+                let num_campers = arguments
+                    .next()
+                    .and_then(|num_campers| num_campers.parse().ok())
+                    .filter(|num_campers| *num_campers > 0)
+                    .expect(USAGE);
+                min_campers = Some(num_campers);
+                continue;
+            }
+            "--mouse" => ClickSourceSettings::LocalMouse,
+            "--script" => {
+                let click_script_path = arguments.next().expect(USAGE);
+                ClickSourceSettings::ScriptedFile(PathBuf::from(click_script_path))
+            }
+            _ => panic!("{}", USAGE),
+        };
+
+        click_sources.push(click_source);
+    }
+
+    // The Heat URL can be given before or after the channel,
+    // so it is only filled in once everything has been read.
+    let mut num_heat_sources = 0;
+    let mut num_mouse_sources = 0;
+    for click_source in click_sources.iter_mut() {
+        match click_source {
+            ClickSourceSettings::HeatApi { url, .. } => {
+                *url = heat_api_url
+                    .clone()
+                    .unwrap_or_else(|| String::from(HEAT_API_URL));
+                num_heat_sources += 1;
+            }
+            ClickSourceSettings::LocalMouse => num_mouse_sources += 1,
+            ClickSourceSettings::ScriptedFile(_) => {}
+        }
+    }
+
+    if heat_api_url.is_some() && num_heat_sources == 0 {
+        panic!(
+            "parse_arguments: --heat-url only applies to a channel given with --heat.\n{}",
+            USAGE
+        );
+    }
+
+    // The game only keeps track of one Heat connection and one mouse,
+    // so each of them can only be listened to once.
+    if num_heat_sources > 1 || num_mouse_sources > 1 {
+        panic!(
+            "parse_arguments: --heat and --mouse can each only be given once.\n{}",
+            USAGE
+        );
+    }
+
+    let mut stream_logic = if click_sources.is_empty() {
+        StreamLogic::default()
+    } else {
+        StreamLogic::new(click_sources)
+    };
+    stream_logic.set_locale(locale);
+    if let Some(voting_time) = voting_time {
+        stream_logic.set_voting_time(voting_time);
+    }
+    if let Some(tie_breaker) = tie_breaker {
+        stream_logic.set_tie_breaker(tie_breaker);
+    }
+    if let Some(lobby_wait_time) = lobby_wait_time {
+        stream_logic.set_lobby_wait_time(lobby_wait_time);
+    }
+    if let Some(min_campers) = min_campers {
+        stream_logic.set_min_campers(min_campers);
+    }
+
+    stream_logic
+}
 
 fn main() {
+    let stream_logic = parse_arguments(std::env::args().skip(1));
+
     let mut streaming_application = App::new();
-    streaming_application.add_plugins(StreamLogic);
+    streaming_application.add_plugins(stream_logic);
 
     streaming_application.run();
 }
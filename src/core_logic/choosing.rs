@@ -0,0 +1,464 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::core_logic::{
+    interacting::{AttemptedObjective, CamperInformation, ViewerClick, ViewerIdentity},
+    phrasing::Locale,
+    progressing::{Landmark, LandmarkScenario},
+    resolving::{GameRng, ScenarioChoiceMade},
+    traveling::CamperJourney,
+};
+
+/// How much of the window's height the scenario panel takes up along
+/// the bottom of the stream, which is 240 out of 720 pixels.
+const PANEL_HEIGHT_PERCENT: f32 = 100.0 / 3.0;
+/// How much of the scenario panel's height is taken up by its title along
+/// the top, which is 60 out of 240 pixels.
+const PANEL_HEADER_HEIGHT_PERCENT: f32 = 25.0;
+/// How much of the scenario panel's height is taken up by the scenario's
+/// description beneath the title, which is also 60 out of 240 pixels. The
+/// choices fill the rest.
+const PANEL_DESCRIPTION_HEIGHT_PERCENT: f32 = 25.0;
+
+/// How long viewers have to vote on what a camper does in a scenario.
+#[derive(Resource, Clone)]
+pub struct VotingTime(Duration);
+
+impl VotingTime {
+    pub fn new(time: Duration) -> Self {
+        Self(time)
+    }
+
+    pub fn get_timer(&self) -> Timer {
+        Timer::new(self.0, TimerMode::Once)
+    }
+}
+
+/// Decides which choice wins when more than one has the most votes.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub enum TieBreaker {
+    /// The tied choice listed first in the scenario wins.
+    FirstListed,
+    /// The tied choice that was voted for first wins.
+    FirstVoted,
+    /// Any of the tied choices could win.
+    #[default]
+    Random,
+}
+
+/// Represents the votes viewers have cast on the active scenario's
+/// choices, and how long they have left to vote.
+#[derive(Resource, Debug)]
+pub struct ScenarioVotes {
+    votes: HashMap<ViewerIdentity, usize>,
+    first_voted_choices: Vec<usize>,
+    countdown: Timer,
+}
+
+impl ScenarioVotes {
+    pub fn new(voting_time: &VotingTime) -> Self {
+        Self {
+            votes: HashMap::new(),
+            first_voted_choices: Vec::new(),
+            countdown: voting_time.get_timer(),
+        }
+    }
+
+    /// Records a viewer's vote for a choice, replacing any vote they made
+    /// before, so that every viewer only ever has one vote.
+    pub fn vote(&mut self, viewer: ViewerIdentity, choice_num: usize) {
+        self.votes.insert(viewer, choice_num);
+        if !self.first_voted_choices.contains(&choice_num) {
+            self.first_voted_choices.push(choice_num);
+        }
+    }
+
+    /// Returns how many viewers are voting for a choice.
+    pub fn get_num_votes(&self, choice_num: usize) -> usize {
+        self.votes
+            .values()
+            .filter(|voted_choice| **voted_choice == choice_num)
+            .count()
+    }
+
+    /// Returns how much of the voting window is left, from 1.0 when voting
+    /// opens down to 0.0 when it closes.
+    pub fn get_fraction_left(&self) -> f32 {
+        self.countdown.fraction_remaining()
+    }
+
+    /// Returns the choice with the most votes, settling ties with the tie
+    /// breaker, or None if nobody has voted.
+    pub fn get_winning_choice(
+        &self,
+        tie_breaker: TieBreaker,
+        game_rng: &mut GameRng,
+    ) -> Option<usize> {
+        let most_votes = self
+            .first_voted_choices
+            .iter()
+            .map(|choice_num| self.get_num_votes(*choice_num))
+            .max()
+            .filter(|most_votes| *most_votes > 0)?;
+
+        // A choice can lose every vote it had when viewers change their mind.
+        let mut tied_choices: Vec<usize> = self
+            .first_voted_choices
+            .iter()
+            .copied()
+            .filter(|choice_num| self.get_num_votes(*choice_num) == most_votes)
+            .collect();
+
+        match tie_breaker {
+            TieBreaker::FirstListed => tied_choices.iter().min().copied(),
+            TieBreaker::FirstVoted => tied_choices.first().copied(),
+            TieBreaker::Random => {
+                tied_choices.sort();
+                Some(tied_choices[game_rng.random_range(0..tied_choices.len())])
+            }
+        }
+    }
+}
+
+/// Represents the scenario a camper in the meadows is going through,
+/// waiting for a choice to be made.
+#[derive(Resource, Clone, Debug)]
+pub struct ActiveScenario {
+    camper: ViewerIdentity,
+    landmark_description: String,
+    scenario: LandmarkScenario,
+}
+
+impl ActiveScenario {
+    pub fn new(
+        camper: ViewerIdentity,
+        landmark_description: String,
+        scenario: LandmarkScenario,
+    ) -> Self {
+        Self {
+            camper,
+            landmark_description,
+            scenario,
+        }
+    }
+
+    /// Returns the viewer whose camper is going through the scenario.
+    pub fn get_camper(&self) -> &ViewerIdentity {
+        &self.camper
+    }
+
+    pub fn get_landmark_description(&self) -> &String {
+        &self.landmark_description
+    }
+
+    pub fn get_scenario(&self) -> &LandmarkScenario {
+        &self.scenario
+    }
+}
+
+/// Marks the panel along the bottom of the stream showing the active
+/// scenario and its choices.
+#[derive(Component)]
+pub struct ScenarioPanel;
+
+/// Marks the title along the top of the scenario panel, asking viewers
+/// what the camper should do.
+#[derive(Component)]
+pub struct ScenarioPanelTitle;
+
+/// Marks the text describing the active scenario.
+#[derive(Component)]
+pub struct ScenarioPanelDescription;
+
+/// Marks the bar that shrinks as the time to vote runs out.
+#[derive(Component)]
+pub struct VotingCountdownBar;
+
+/// Marks one of the boxes viewers click on to make a choice, holding
+/// which choice of the active scenario it is.
+#[derive(Component)]
+pub struct ChoiceBox(usize);
+
+impl ChoiceBox {
+    pub fn get_choice_num(&self) -> usize {
+        self.0
+    }
+}
+
+/// Marks the text showing how many votes a choice has, holding which
+/// choice of the active scenario it is.
+#[derive(Component)]
+pub struct ChoiceVoteCount(usize);
+
+impl ChoiceVoteCount {
+    pub fn get_choice_num(&self) -> usize {
+        self.0
+    }
+}
+
+/// Returns how the number of votes for a choice reads on the stream.
+fn get_vote_count_text(num_votes: usize, locale: &Locale) -> String {
+    let vote_count_text = match num_votes {
+        1 => locale.get_text("one_vote", &[]),
+        _ => locale.get_text("many_votes", &[("{count}", num_votes.to_string())]),
+    };
+
+    format!("\n{}", vote_count_text)
+}
+
+/// Returns which choice of the scenario panel a viewer clicked on, or
+/// None if they clicked anywhere else on the stream.
+///
+/// The choices are laid out side by side, each taking an equal share of
+/// the panel's width beneath the panel's title and scenario's description.
+pub fn get_clicked_choice(viewer_click: &ViewerClick, num_choices: usize) -> Option<usize> {
+    let choices_height = (PANEL_HEIGHT_PERCENT / 100.0)
+        * (1.0 - (PANEL_HEADER_HEIGHT_PERCENT + PANEL_DESCRIPTION_HEIGHT_PERCENT) / 100.0);
+    let within_choices = (0.0..choices_height).contains(&viewer_click.get_y())
+        && (0.0..=1.0).contains(&viewer_click.get_x());
+    if num_choices == 0 || !within_choices {
+        return None;
+    }
+
+    let choice_num = (viewer_click.get_x() * num_choices as f32) as usize;
+    Some(choice_num.min(num_choices - 1))
+}
+
+/// Puts a scenario in front of the next camper waiting in the meadows,
+/// picked at random from the scenarios of the objective they attempted,
+/// and opens voting on what they should do.
+pub fn present_scenario_to_campers(
+    active_scenario: Option<Res<ActiveScenario>>,
+    campers: Query<(&CamperInformation, &CamperJourney, &AttemptedObjective)>,
+    landmarks: Query<&Landmark>,
+    voting_time: Res<VotingTime>,
+    mut game_rng: ResMut<GameRng>,
+    mut commands: Commands,
+) {
+    if active_scenario.is_some() {
+        return;
+    }
+
+    for (camper_info, camper_journey, attempted_objective) in &campers {
+        if *camper_journey != CamperJourney::InMeadows {
+            continue;
+        }
+
+        let possible_scenarios: Vec<(&Landmark, &LandmarkScenario)> = landmarks
+            .iter()
+            .flat_map(|landmark| {
+                landmark
+                    .get_scenarios()
+                    .iter()
+                    .map(move |scenario| (landmark, scenario))
+            })
+            .filter(|(_landmark, scenario)| {
+                scenario.get_type() == *attempted_objective.get_objective()
+            })
+            .collect();
+        if possible_scenarios.is_empty() {
+            continue;
+        }
+
+        let (landmark, scenario) =
+            possible_scenarios[game_rng.random_range(0..possible_scenarios.len())];
+        commands.insert_resource(ActiveScenario::new(
+            camper_info.get_viewer().clone(),
+            landmark.get_description(),
+            scenario.clone(),
+        ));
+        commands.insert_resource(ScenarioVotes::new(&voting_time));
+        return;
+    }
+}
+
+/// Counts a vote for every choice of the active scenario viewers click on.
+pub fn collect_scenario_votes(
+    mut viewer_clicks: MessageReader<ViewerClick>,
+    active_scenario: Option<Res<ActiveScenario>>,
+    scenario_votes: Option<ResMut<ScenarioVotes>>,
+) {
+    let (Some(active_scenario), Some(mut scenario_votes)) = (active_scenario, scenario_votes)
+    else {
+        viewer_clicks.clear();
+        return;
+    };
+
+    let num_choices = active_scenario.get_scenario().get_choices().len();
+    for viewer_click in viewer_clicks.read() {
+        if let Some(choice_num) = get_clicked_choice(viewer_click, num_choices) {
+            scenario_votes.vote(viewer_click.get_viewer().clone(), choice_num);
+        }
+    }
+}
+
+/// Counts down the time left to vote, making the choice with the most votes
+/// once time runs out. Voting starts over when nobody has voted.
+pub fn close_scenario_voting(
+    time: Res<Time>,
+    active_scenario: Res<ActiveScenario>,
+    mut scenario_votes: ResMut<ScenarioVotes>,
+    tie_breaker: Res<TieBreaker>,
+    mut game_rng: ResMut<GameRng>,
+    mut scenario_choice_broadcaster: MessageWriter<ScenarioChoiceMade>,
+    mut commands: Commands,
+) {
+    scenario_votes.countdown.tick(time.delta());
+    if !scenario_votes.countdown.is_finished() {
+        return;
+    }
+
+    let Some(winning_choice) = scenario_votes.get_winning_choice(*tie_breaker, &mut game_rng)
+    else {
+        scenario_votes.countdown.reset();
+        return;
+    };
+
+    let scenario = active_scenario.get_scenario();
+    scenario_choice_broadcaster.write(ScenarioChoiceMade::new(
+        active_scenario.get_camper().clone(),
+        scenario.get_type(),
+        scenario.get_choice(winning_choice).clone(),
+        scenario_votes.get_num_votes(winning_choice),
+    ));
+    commands.remove_resource::<ActiveScenario>();
+    commands.remove_resource::<ScenarioVotes>();
+}
+
+/// Keeps the vote counts and the time left to vote up to date on the
+/// scenario panel.
+pub fn update_voting_display(
+    scenario_votes: Res<ScenarioVotes>,
+    mut vote_counts: Query<(&mut TextSpan, &ChoiceVoteCount)>,
+    mut countdown_bars: Query<&mut Node, With<VotingCountdownBar>>,
+    locale: Res<Locale>,
+) {
+    for (mut vote_count_text, vote_count) in &mut vote_counts {
+        let num_votes = scenario_votes.get_num_votes(vote_count.get_choice_num());
+        vote_count_text.0 = get_vote_count_text(num_votes, &locale);
+    }
+
+    for mut countdown_bar in &mut countdown_bars {
+        countdown_bar.width = Val::Percent(scenario_votes.get_fraction_left() * 100.0);
+    }
+}
+
+/// Shows the active scenario along the bottom of the stream, with a box
+/// for each of its choices.
+pub fn show_scenario_panel(
+    active_scenario: Res<ActiveScenario>,
+    scenario_panels: Query<Entity, With<ScenarioPanel>>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    for scenario_panel in &scenario_panels {
+        commands.entity(scenario_panel).despawn();
+    }
+
+    let scenario = active_scenario.get_scenario();
+    let scenario_description = format!(
+        "{} {}",
+        active_scenario.get_landmark_description(),
+        scenario.get_description()
+    );
+
+    commands
+        .spawn((
+            ScenarioPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(PANEL_HEIGHT_PERCENT),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        ))
+        .with_children(|scenario_panel| {
+            scenario_panel.spawn((
+                VotingCountdownBar,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Px(6.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(1.0, 0.8, 0.2)),
+            ));
+
+            scenario_panel.spawn((
+                ScenarioPanelTitle,
+                Text::new(locale.get_text("scenario_title", &[])),
+                TextFont::from_font_size(36.0),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(PANEL_HEADER_HEIGHT_PERCENT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ));
+
+            scenario_panel.spawn((
+                ScenarioPanelDescription,
+                Text::new(scenario_description),
+                TextFont::from_font_size(18.0),
+                Node {
+                    height: Val::Percent(PANEL_DESCRIPTION_HEIGHT_PERCENT),
+                    padding: UiRect::horizontal(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+
+            scenario_panel
+                .spawn(Node {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Row,
+                    ..default()
+                })
+                .with_children(|choice_row| {
+                    for (choice_num, choice) in scenario.get_choices().iter().enumerate() {
+                        let vote_count = (
+                            ChoiceVoteCount(choice_num),
+                            TextSpan::new(get_vote_count_text(0, &locale)),
+                            TextFont::from_font_size(18.0),
+                        );
+
+                        choice_row.spawn((
+                            ChoiceBox(choice_num),
+                            Text::new(choice.get_description()),
+                            TextFont::from_font_size(24.0),
+                            TextLayout::new_with_justify(Justify::Center),
+                            Node {
+                                flex_grow: 1.0,
+                                flex_basis: Val::Px(0.0),
+                                margin: UiRect::all(Val::Px(8.0)),
+                                padding: UiRect::all(Val::Px(8.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            BorderColor::all(Color::WHITE),
+                            children![vote_count],
+                        ));
+                    }
+                });
+        });
+}
+
+/// Takes the scenario panel off of the stream once voting has closed.
+pub fn hide_scenario_panel(
+    scenario_panels: Query<Entity, With<ScenarioPanel>>,
+    mut commands: Commands,
+) {
+    for scenario_panel in &scenario_panels {
+        commands.entity(scenario_panel).despawn();
+    }
+}
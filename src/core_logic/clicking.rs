@@ -0,0 +1,436 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::*;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::core_logic::interacting::{ViewerClick, ViewerIdentity};
+
+/// Who clicks made with the local mouse are said to come from.
+pub const LOCAL_MOUSE_VIEWER_ID: &str = "streamer";
+
+/// Who scripted clicks come from when the script does not say.
+pub const SCRIPTED_VIEWER_ID: &str = "scripted-viewer";
+
+/// How long the game counts clicks for when capping how many it accepts.
+const CLICK_CAP_PERIOD: Duration = Duration::from_secs(1);
+
+/// Represents anywhere clicks from viewers can come from, such as
+/// Twitch, the local mouse, or a script.
+pub trait ClickSource: Send + Sync {
+    /// Returns the next click waiting to be handled, or None if
+    /// there are no clicks waiting right now.
+    fn read(&mut self) -> Option<ViewerClick>;
+}
+
+/// The list of places the game listens to for viewer clicks.
+#[derive(Resource, Default)]
+pub struct ClickSources {
+    sources: Vec<Box<dyn ClickSource>>,
+}
+
+impl ClickSources {
+    pub fn add(&mut self, click_source: impl ClickSource + 'static) {
+        self.sources.push(Box::new(click_source));
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// Determines how many clicks the game accepts, so that one viewer with
+/// an autoclicker cannot drown out everyone else.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ClickLimits {
+    viewer_cooldown: Duration,
+    max_clicks_per_second: Option<usize>,
+}
+
+impl ClickLimits {
+    pub fn new(viewer_cooldown: Duration) -> Self {
+        Self {
+            viewer_cooldown,
+            max_clicks_per_second: None,
+        }
+    }
+
+    /// Sets how many clicks the game accepts from everyone in a second.
+    ///
+    /// Without this, there is no limit on clicks across every viewer.
+    pub fn set_max_clicks_per_second(&mut self, max_clicks_per_second: usize) {
+        self.max_clicks_per_second = Some(max_clicks_per_second);
+    }
+
+    /// Returns how long a viewer has to wait after a click before their
+    /// next one is accepted.
+    pub fn get_viewer_cooldown(&self) -> Duration {
+        self.viewer_cooldown
+    }
+
+    pub fn get_max_clicks_per_second(&self) -> Option<usize> {
+        self.max_clicks_per_second
+    }
+}
+
+/// Keeps track of recent clicks to decide which ones are within the
+/// Click Limits, counting every click that is dropped.
+#[derive(Resource, Debug, Default)]
+pub struct ClickLimiter {
+    last_accepted_clicks: HashMap<ViewerIdentity, Duration>,
+    period_started_at: Duration,
+    num_clicks_this_period: usize,
+    num_dropped_this_period: usize,
+    num_dropped_last_period: Option<usize>,
+    num_dropped_on_cooldown: usize,
+    num_dropped_over_cap: usize,
+}
+
+impl ClickLimiter {
+    /// Returns whether a viewer's click made at the given time is within
+    /// the Click Limits, counting it as dropped otherwise.
+    pub fn accept(
+        &mut self,
+        click_limits: &ClickLimits,
+        viewer: &ViewerIdentity,
+        clicked_at: Duration,
+    ) -> bool {
+        if clicked_at.saturating_sub(self.period_started_at) >= CLICK_CAP_PERIOD {
+            self.start_period(click_limits, clicked_at);
+        }
+
+        let on_cooldown = self
+            .last_accepted_clicks
+            .get(viewer)
+            .is_some_and(|last_clicked_at| {
+                clicked_at.saturating_sub(*last_clicked_at) < click_limits.get_viewer_cooldown()
+            });
+        if on_cooldown {
+            self.num_dropped_on_cooldown += 1;
+            self.num_dropped_this_period += 1;
+            return false;
+        }
+
+        let over_cap = click_limits
+            .get_max_clicks_per_second()
+            .is_some_and(|max_clicks| self.num_clicks_this_period >= max_clicks);
+        if over_cap {
+            self.num_dropped_over_cap += 1;
+            self.num_dropped_this_period += 1;
+            return false;
+        }
+
+        self.last_accepted_clicks.insert(viewer.clone(), clicked_at);
+        self.num_clicks_this_period += 1;
+        true
+    }
+
+    /// Starts counting clicks towards the cap again, forgetting viewers
+    /// whose cooldown is over so that they are not remembered forever.
+    fn start_period(&mut self, click_limits: &ClickLimits, started_at: Duration) {
+        if self.num_dropped_this_period > 0 {
+            self.num_dropped_last_period = Some(self.num_dropped_this_period);
+        }
+
+        self.last_accepted_clicks
+            .retain(|_viewer, last_clicked_at| {
+                started_at.saturating_sub(*last_clicked_at) < click_limits.get_viewer_cooldown()
+            });
+        self.period_started_at = started_at;
+        self.num_clicks_this_period = 0;
+        self.num_dropped_this_period = 0;
+    }
+
+    /// Returns how many clicks were dropped for coming too soon after the
+    /// same viewer's last click.
+    pub fn get_num_dropped_on_cooldown(&self) -> usize {
+        self.num_dropped_on_cooldown
+    }
+
+    /// Returns how many clicks were dropped for going over the cap on
+    /// clicks from everyone.
+    pub fn get_num_dropped_over_cap(&self) -> usize {
+        self.num_dropped_over_cap
+    }
+
+    /// Returns how many clicks were dropped in the last second that had
+    /// any dropped, or None if that has already been reported.
+    pub fn take_num_dropped_last_period(&mut self) -> Option<usize> {
+        self.num_dropped_last_period.take()
+    }
+}
+
+/// A Click Source fed by anything holding its sender, such as tests
+/// or other parts of the game.
+pub struct ChannelClickSource {
+    click_receiver: UnboundedReceiver<ViewerClick>,
+}
+
+/// Sends clicks to its paired Channel Click Source.
+#[derive(Clone, Debug)]
+pub struct ChannelClickSender {
+    click_sender: UnboundedSender<ViewerClick>,
+}
+
+impl ChannelClickSource {
+    pub fn new() -> (Self, ChannelClickSender) {
+        let (click_sender, click_receiver) = mpsc::unbounded_channel();
+
+        (Self { click_receiver }, ChannelClickSender { click_sender })
+    }
+}
+
+impl ClickSource for ChannelClickSource {
+    fn read(&mut self) -> Option<ViewerClick> {
+        self.click_receiver.try_recv().ok()
+    }
+}
+
+impl ChannelClickSender {
+    /// Sends a click to the Channel Click Source, returning whether
+    /// it is still around to receive it.
+    pub fn send(&self, viewer_click: ViewerClick) -> bool {
+        self.click_sender.send(viewer_click).is_ok()
+    }
+}
+
+/// A click written in a click script, happening some time after the
+/// script starts.
+///
+/// Coordinates follow the Heat API, where (0, 0) is the top-left corner
+/// of the stream and (1, 1) is the bottom-right corner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptedClick {
+    delay: Duration,
+    x: f32,
+    y: f32,
+    viewer_id: String,
+}
+
+impl ScriptedClick {
+    pub fn new(delay: Duration, x: f32, y: f32) -> Self {
+        Self {
+            delay,
+            x,
+            y,
+            viewer_id: String::from(SCRIPTED_VIEWER_ID),
+        }
+    }
+
+    pub fn set_viewer_id(&mut self, viewer_id: String) {
+        self.viewer_id = viewer_id;
+    }
+
+    pub fn get_viewer_id(&self) -> &String {
+        &self.viewer_id
+    }
+
+    pub fn get_delay(&self) -> Duration {
+        self.delay
+    }
+
+    pub fn get_x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn get_y(&self) -> f32 {
+        self.y
+    }
+
+    /// Returns the click as seen by the game, whose y-axis goes up.
+    pub fn to_viewer_click(&self) -> ViewerClick {
+        let viewer = ViewerIdentity::new(self.viewer_id.clone());
+        ViewerClick::new(viewer, self.x, 1.0 - self.y)
+    }
+}
+
+/// Returns a list of Scripted Clicks sorted by when they happen, parsed
+/// from lines in the form of `<seconds> <x> <y> [viewer id]`.
+///
+/// Blank lines and lines starting with '#' are ignored.
+pub fn parse_click_script(click_script: &str) -> Result<Vec<ScriptedClick>, String> {
+    let mut scripted_clicks = Vec::new();
+
+    for (line_idx, line) in click_script.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line_num = line_idx + 1;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (delay, x, y, viewer_id) = match fields[..] {
+            [delay, x, y] => (delay, x, y, None),
+            [delay, x, y, viewer_id] => (delay, x, y, Some(viewer_id)),
+            _ => {
+                return Err(format!(
+                    "Line {}: Expected '<seconds> <x> <y> [viewer id]', got '{}'",
+                    line_num, line
+                ));
+            }
+        };
+
+        let delay = delay
+            .parse::<f32>()
+            .ok()
+            .and_then(|delay| Duration::try_from_secs_f32(delay).ok())
+            .ok_or_else(|| format!("Line {}: Invalid number of seconds '{}'", line_num, delay))?;
+        let x = x
+            .parse::<f32>()
+            .map_err(|_| format!("Line {}: Invalid x coordinate '{}'", line_num, x))?;
+        let y = y
+            .parse::<f32>()
+            .map_err(|_| format!("Line {}: Invalid y coordinate '{}'", line_num, y))?;
+
+        let mut scripted_click = ScriptedClick::new(delay, x, y);
+        if let Some(viewer_id) = viewer_id {
+            scripted_click.set_viewer_id(viewer_id.to_string());
+        }
+
+        scripted_clicks.push(scripted_click);
+    }
+
+    scripted_clicks.sort_by_key(|scripted_click| scripted_click.get_delay());
+    Ok(scripted_clicks)
+}
+
+/// A Click Source that replays clicks from a click script, starting
+/// from the first time it is read from.
+pub struct ScriptedClickSource {
+    scripted_clicks: Vec<ScriptedClick>,
+    next_click_idx: usize,
+    started_at: Option<Instant>,
+}
+
+impl ScriptedClickSource {
+    pub fn new(scripted_clicks: Vec<ScriptedClick>) -> Self {
+        Self {
+            scripted_clicks,
+            next_click_idx: 0,
+            started_at: None,
+        }
+    }
+
+    /// Returns a Scripted Click Source from a click script file, or panics
+    /// if the file cannot be read or understood.
+    pub fn from_file(click_script_path: &Path) -> Self {
+        let click_script = fs::read_to_string(click_script_path).unwrap_or_else(|_| {
+            panic!(
+                "from_file: Could not read click script {}",
+                click_script_path.display()
+            )
+        });
+        let scripted_clicks = parse_click_script(&click_script).unwrap_or_else(|parse_error| {
+            panic!(
+                "from_file: Could not parse click script {}: {}",
+                click_script_path.display(),
+                parse_error
+            )
+        });
+
+        Self::new(scripted_clicks)
+    }
+}
+
+impl ClickSource for ScriptedClickSource {
+    fn read(&mut self) -> Option<ViewerClick> {
+        let started_at = *self.started_at.get_or_insert_with(Instant::now);
+
+        let next_click = self.scripted_clicks.get(self.next_click_idx)?;
+        if started_at.elapsed() < next_click.get_delay() {
+            return None;
+        }
+
+        self.next_click_idx += 1;
+        Some(next_click.to_viewer_click())
+    }
+}
+
+/// A Click Source for clicks made with the mouse on the game's own window,
+/// so that a stream can be rehearsed offline.
+pub struct LocalMouseClickSource(ChannelClickSource);
+
+/// Where clicks from the mouse are sent to reach the Local Mouse Click Source.
+#[derive(Resource)]
+pub struct LocalMouseClicks(ChannelClickSender);
+
+impl LocalMouseClickSource {
+    pub fn new() -> (Self, LocalMouseClicks) {
+        let (click_source, click_sender) = ChannelClickSource::new();
+
+        (Self(click_source), LocalMouseClicks(click_sender))
+    }
+}
+
+impl ClickSource for LocalMouseClickSource {
+    fn read(&mut self) -> Option<ViewerClick> {
+        self.0.read()
+    }
+}
+
+/// Converts left clicks on the game window into the same UV coordinates
+/// viewers would send.
+pub fn capture_local_mouse_clicks(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window>,
+    local_mouse_clicks: Res<LocalMouseClicks>,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(cursor_position) = window.cursor_position() else {
+        return;
+    };
+
+    let uv_x = cursor_position.x / window.width();
+    let uv_y = 1.0 - (cursor_position.y / window.height());
+    let streamer = ViewerIdentity::new(String::from(LOCAL_MOUSE_VIEWER_ID));
+    local_mouse_clicks
+        .0
+        .send(ViewerClick::new(streamer, uv_x, uv_y));
+}
+
+/// Forwards clicks from every Click Source into the game, dropping any
+/// that go past the Click Limits.
+pub fn read_click_sources(
+    mut click_sources: ResMut<ClickSources>,
+    click_limits: Res<ClickLimits>,
+    mut click_limiter: ResMut<ClickLimiter>,
+    time: Res<Time<Real>>,
+    mut viewer_click_broadcaster: MessageWriter<ViewerClick>,
+) {
+    let clicked_at = time.elapsed();
+    for click_source in click_sources.sources.iter_mut() {
+        while let Some(viewer_click) = click_source.read() {
+            if !click_limiter.accept(&click_limits, viewer_click.get_viewer(), clicked_at) {
+                continue;
+            }
+
+            viewer_click_broadcaster.write(viewer_click);
+        }
+    }
+}
+
+/// Lets the streamer know whenever clicks were dropped for going past the
+/// Click Limits.
+pub fn report_dropped_clicks(mut click_limiter: ResMut<ClickLimiter>) {
+    let Some(num_dropped_clicks) = click_limiter.take_num_dropped_last_period() else {
+        return;
+    };
+
+    warn!(
+        "report_dropped_clicks: Dropped {} clicks in the last second. {} on cooldown and {} over the cap so far.",
+        num_dropped_clicks,
+        click_limiter.get_num_dropped_on_cooldown(),
+        click_limiter.get_num_dropped_over_cap()
+    );
+}
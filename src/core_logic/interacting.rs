@@ -1,21 +1,118 @@
+use std::hash::{Hash, Hasher};
+
 use bevy::prelude::*;
 
-use crate::core_logic::traveling::Pathfinding;
+use crate::core_logic::{
+    progressing::ScenarioChoice,
+    traveling::{CamperJourney, Pathfinding},
+};
+
+use super::setting::{
+    LogicalCoordinates, TileSize, WorldTileDimensions, get_logical_from_physical,
+};
+
+/// The id shared by every viewer whose clicks came without one.
+pub const ANONYMOUS_VIEWER_ID: &str = "Anonymous";
+
+/// Represents who some viewer is, as told by where their clicks came from.
+///
+/// The id is all that is needed to tell viewers apart. It is either the
+/// viewer's Twitch user id, or an opaque id when they have not shared their
+/// identity. Their login and display name are only known once resolved.
+#[derive(Clone, Debug, Default, Eq)]
+pub struct ViewerIdentity {
+    id: String,
+    login: Option<String>,
+    display_name: Option<String>,
+}
+
+impl ViewerIdentity {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            login: None,
+            display_name: None,
+        }
+    }
+
+    /// Returns the identity of a viewer whose clicks came without an id,
+    /// who shares a camper with every other such viewer.
+    pub fn anonymous() -> Self {
+        Self::new(String::from(ANONYMOUS_VIEWER_ID))
+    }
+
+    pub fn set_login(&mut self, login: String) {
+        self.login = Some(login);
+    }
+
+    pub fn set_display_name(&mut self, display_name: String) {
+        self.display_name = Some(display_name);
+    }
+
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn get_login(&self) -> Option<&String> {
+        self.login.as_ref()
+    }
 
-use super::setting::{LogicalCoordinates, TileSize};
+    pub fn get_display_name(&self) -> Option<&String> {
+        self.display_name.as_ref()
+    }
+
+    /// Returns the most readable name known for the viewer, preferring
+    /// their display name, then their login, then their id.
+    pub fn get_name(&self) -> &String {
+        self.display_name
+            .as_ref()
+            .or(self.login.as_ref())
+            .unwrap_or(&self.id)
+    }
+}
+
+impl PartialEq for ViewerIdentity {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for ViewerIdentity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
 
 #[derive(Component)]
 pub struct CamperInformation {
-    camper_name: String,
+    viewer: ViewerIdentity,
 }
 
 impl CamperInformation {
-    pub fn new(camper_name: String) -> Self {
-        Self { camper_name }
+    pub fn new(viewer: ViewerIdentity) -> Self {
+        Self { viewer }
     }
 
     pub fn get_camper_name(&self) -> &String {
-        &self.camper_name
+        self.viewer.get_name()
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+}
+
+/// Represents the objective a camper set out to the meadows to complete.
+#[derive(Component, Clone, Debug)]
+pub struct AttemptedObjective(String);
+
+impl AttemptedObjective {
+    pub fn new(objective: String) -> Self {
+        Self(objective)
+    }
+
+    pub fn get_objective(&self) -> &String {
+        &self.0
     }
 }
 
@@ -25,46 +122,57 @@ pub struct CamperBundle {
     camper_physical_location: Transform,
     camper_logical_location: LogicalCoordinates,
     travel_path: Pathfinding,
+    journey: CamperJourney,
 }
 
 impl CamperBundle {
     pub fn new(
-        camper_name: &String,
+        viewer: &ViewerIdentity,
         camper_physical_location: Transform,
         camper_logical_location: LogicalCoordinates,
         travel_path: Pathfinding,
+        journey: CamperJourney,
     ) -> Self {
-        let camper_info = CamperInformation::new(camper_name.clone());
+        let camper_info = CamperInformation::new(viewer.clone());
 
         Self {
             camper_info,
             camper_physical_location,
             camper_logical_location,
             travel_path,
+            journey,
         }
     }
 }
 
 #[derive(Message)]
 pub struct ObjectiveAttempt {
-    player_name: String,
+    viewer: ViewerIdentity,
     objective_attempted: String,
 }
 
 impl ObjectiveAttempt {
-    pub fn new(player_name: String, objective_attempted: String) -> Self {
+    pub fn new(viewer: ViewerIdentity, objective_attempted: String) -> Self {
         Self {
-            player_name,
+            viewer,
             objective_attempted,
         }
     }
 
     pub fn get_camper_name(&self) -> &String {
-        &self.player_name
+        self.viewer.get_name()
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+
+    pub fn get_objective(&self) -> &String {
+        &self.objective_attempted
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ScenarioResult {
     Fail,
     Success,
@@ -72,26 +180,32 @@ pub enum ScenarioResult {
 
 #[derive(Message)]
 pub struct ScenarioAttempt {
-    player_name: String,
+    viewer: ViewerIdentity,
     objective_completed: String,
     attempt_status: ScenarioResult,
+    choice_made: Option<ScenarioChoice>,
 }
 
 impl ScenarioAttempt {
     pub fn new(
-        player_name: String,
+        viewer: ViewerIdentity,
         objective_completed: String,
         attempt_status: ScenarioResult,
     ) -> Self {
         Self {
-            player_name,
+            viewer,
             objective_completed,
             attempt_status,
+            choice_made: None,
         }
     }
 
     pub fn get_camper_name(&self) -> &String {
-        &self.player_name
+        self.viewer.get_name()
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
     }
 
     pub fn get_objective(&self) -> &String {
@@ -101,17 +215,31 @@ impl ScenarioAttempt {
     pub fn get_status(&self) -> ScenarioResult {
         self.attempt_status
     }
+
+    /// Sets which choice of the scenario the camper went with.
+    pub fn set_choice(&mut self, choice_made: ScenarioChoice) {
+        self.choice_made = Some(choice_made);
+    }
+
+    pub fn get_choice(&self) -> Option<&ScenarioChoice> {
+        self.choice_made.as_ref()
+    }
 }
 
-#[derive(Message)]
+#[derive(Message, Clone, Debug)]
 pub struct ViewerClick {
+    viewer: ViewerIdentity,
     uv_x: f32,
     uv_y: f32,
 }
 
 impl ViewerClick {
-    pub fn new(uv_x: f32, uv_y: f32) -> Self {
-        Self { uv_x, uv_y }
+    pub fn new(viewer: ViewerIdentity, uv_x: f32, uv_y: f32) -> Self {
+        Self { viewer, uv_x, uv_y }
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
     }
 
     pub fn get_x(&self) -> f32 {
@@ -123,57 +251,71 @@ impl ViewerClick {
     }
 }
 
-fn convert_to_coords(
-    uv_coordinates: &ViewerClick,
-    window_width: f32,
-    window_height: f32,
-) -> Transform {
+/// Represents a viewer clicking on some tile of the map.
+#[derive(Message, Clone, Debug)]
+pub struct TileClicked {
+    viewer: ViewerIdentity,
+    coordinates: LogicalCoordinates,
+}
+
+impl TileClicked {
+    pub fn new(viewer: ViewerIdentity, coordinates: LogicalCoordinates) -> Self {
+        Self {
+            viewer,
+            coordinates,
+        }
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+
+    pub fn get_coordinates(&self) -> &LogicalCoordinates {
+        &self.coordinates
+    }
+}
+
+/// Returns where on the window the click happened in pixels, relative to
+/// the center of the window.
+fn convert_to_coords(uv_coordinates: &ViewerClick, window_width: f32, window_height: f32) -> Vec2 {
     let coordinate_offset_x = window_width / 2.0;
     let coordinate_offset_y = window_height / 2.0;
 
     let screen_x = (uv_coordinates.get_x() * window_width) - coordinate_offset_x;
     let screen_y = (uv_coordinates.get_y() * window_height) - coordinate_offset_y;
 
-    Transform::from_xyz(screen_x, screen_y, 2.0)
-}
-
-fn adjust_from_camera(screen_coordinates: Transform, camera: &Transform) -> Transform {
-    *camera * screen_coordinates
+    Vec2::new(screen_x, screen_y)
 }
 
-fn convert_to_tilepos(game_coordinates: Transform, tile_size: f32) -> LogicalCoordinates {
-    let x_pos = (game_coordinates.translation.x / tile_size).round();
-    let y_pos = (game_coordinates.translation.y / tile_size).round();
-    LogicalCoordinates::new(x_pos as usize, y_pos as usize, 0)
+/// Returns where in the game a click on the window happened, as seen from a
+/// camera zoomed in by the map's scale.
+fn adjust_from_camera(screen_coordinates: Vec2, camera: &Transform, map_scale: f32) -> Vec2 {
+    camera.translation.truncate() + (screen_coordinates / map_scale)
 }
 
+/// Converts clicks from viewers into clicks on the tiles of the loaded map.
 pub fn convert_viewer_click_to_tile_click(
     mut viewer_clicks: MessageReader<ViewerClick>,
-    mut movement_broadcaster: MessageWriter<LogicalCoordinates>,
-    window_info: Query<&Window>,
-    tile_scaling: Res<TileSize>,
-    camera: Query<&Transform, With<Camera2d>>,
+    mut tile_click_broadcaster: MessageWriter<TileClicked>,
+    window: Single<&Window>,
+    tile_size: Res<TileSize>,
+    map_size: Single<&WorldTileDimensions>,
+    camera: Single<&Transform, With<Camera2d>>,
 ) {
-    if window_info.is_empty() || camera.is_empty() {
-        return;
-    }
-
-    let window = window_info.single().expect(
-        "convert_viewer_click_to_tile_click: Could not get information on the game Window.",
-    );
-    let window_width = window.physical_width() as f32;
-    let window_height = window.physical_height() as f32;
-    let tile_size = tile_scaling.calculate_size() as f32;
-
-    let camera_position = camera
-        .single()
-        .expect("convert_viewer_click_to_tile_click: Could not get the camera.");
+    let window_width = window.width();
+    let window_height = window.height();
+    let map_scale = tile_size.get_scale() as f32;
 
     for viewer_click in viewer_clicks.read() {
-        let game_coords = convert_to_coords(viewer_click, window_width, window_height);
-        let adjusted_game_coords = adjust_from_camera(game_coords, camera_position);
-        let converted_tile_pos = convert_to_tilepos(adjusted_game_coords, tile_size);
+        let screen_coords = convert_to_coords(viewer_click, window_width, window_height);
+        let game_coords = adjust_from_camera(screen_coords, &camera, map_scale);
+        let Some(tile_coords) =
+            get_logical_from_physical(game_coords, tile_size.get_size(), &map_size)
+        else {
+            continue;
+        };
 
-        movement_broadcaster.write(converted_tile_pos);
+        let viewer = viewer_click.get_viewer().clone();
+        tile_click_broadcaster.write(TileClicked::new(viewer, tile_coords));
     }
 }
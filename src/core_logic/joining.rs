@@ -0,0 +1,316 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::core_logic::{
+    CampersState,
+    interacting::{ViewerClick, ViewerIdentity},
+    phrasing::Locale,
+};
+
+/// Determines when the campers leave the lobby for their trip.
+#[derive(Resource, Clone, Debug)]
+pub struct LobbyRules {
+    wait_time: Duration,
+    min_campers: usize,
+}
+
+impl Default for LobbyRules {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60), 1)
+    }
+}
+
+impl LobbyRules {
+    pub fn new(wait_time: Duration, min_campers: usize) -> Self {
+        Self {
+            wait_time,
+            min_campers,
+        }
+    }
+
+    /// Returns how long the lobby stays open before the trip begins.
+    pub fn get_wait_time(&self) -> Duration {
+        self.wait_time
+    }
+
+    /// Returns how many campers have to join before the trip can begin.
+    pub fn get_min_campers(&self) -> usize {
+        self.min_campers
+    }
+}
+
+/// Represents the viewers who joined the trip from the lobby, where each
+/// viewer's camper is numbered by the order they joined in.
+///
+/// Once the lobby closes, only the viewers on the roster have campers on
+/// the trip. Without a lobby, every viewer does.
+#[derive(Resource, Debug, Default)]
+pub struct CamperRoster {
+    campers: Vec<ViewerIdentity>,
+    is_closed: bool,
+}
+
+impl CamperRoster {
+    /// Adds a viewer to the roster, returning whether they had not
+    /// joined already.
+    pub fn join(&mut self, viewer: ViewerIdentity) -> bool {
+        if self.campers.contains(&viewer) {
+            return false;
+        }
+
+        self.campers.push(viewer);
+        true
+    }
+
+    /// Returns the number of the camper assigned to a viewer, starting
+    /// from 1, or None if they have not joined.
+    pub fn get_camper_num(&self, viewer: &ViewerIdentity) -> Option<usize> {
+        self.campers
+            .iter()
+            .position(|camper| camper == viewer)
+            .map(|camper_idx| camper_idx + 1)
+    }
+
+    /// Stops anyone else from joining, leaving the trip to the campers
+    /// already on the roster.
+    pub fn close(&mut self) {
+        self.is_closed = true;
+    }
+
+    /// Returns whether a viewer has a camper on the trip.
+    pub fn has_camper(&self, viewer: &ViewerIdentity) -> bool {
+        !self.is_closed || self.campers.contains(viewer)
+    }
+
+    pub fn get_campers(&self) -> &Vec<ViewerIdentity> {
+        &self.campers
+    }
+
+    pub fn len(&self) -> usize {
+        self.campers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.campers.is_empty()
+    }
+}
+
+/// Counts down to when the lobby closes.
+#[derive(Resource)]
+pub struct LobbyCountdown(Timer);
+
+impl LobbyCountdown {
+    pub fn new(lobby_rules: &LobbyRules) -> Self {
+        Self(Timer::new(lobby_rules.get_wait_time(), TimerMode::Once))
+    }
+
+    pub fn get_timer(&self) -> &Timer {
+        &self.0
+    }
+}
+
+/// Sent by the streamer to begin the trip without waiting for the lobby
+/// countdown.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct StartTrip;
+
+/// Marks the title screen shown while viewers join.
+#[derive(Component)]
+pub struct LobbyScreen;
+
+/// Marks the game's title shown on the title screen.
+#[derive(Component)]
+pub struct LobbyTitle;
+
+/// Marks the text telling viewers when the trip begins.
+#[derive(Component)]
+pub struct LobbyStatus;
+
+/// Marks the text listing every camper who joined.
+#[derive(Component)]
+pub struct LobbyRoster;
+
+/// Returns what the lobby says about when the trip begins.
+fn get_lobby_status_text(
+    camper_roster: &CamperRoster,
+    lobby_rules: &LobbyRules,
+    lobby_countdown: &LobbyCountdown,
+    locale: &Locale,
+) -> String {
+    let num_campers_missing = lobby_rules
+        .get_min_campers()
+        .saturating_sub(camper_roster.len());
+    match num_campers_missing {
+        0 => {
+            let seconds_left = lobby_countdown.get_timer().remaining().as_secs_f32().ceil() as u64;
+            locale.get_text("bus_leaving", &[("{seconds}", seconds_left.to_string())])
+        }
+        1 => locale.get_text("camper_missing", &[]),
+        _ => locale.get_text(
+            "campers_missing",
+            &[("{count}", num_campers_missing.to_string())],
+        ),
+    }
+}
+
+/// Returns the list of campers shown in the lobby.
+fn get_lobby_roster_text(camper_roster: &CamperRoster, locale: &Locale) -> String {
+    if camper_roster.is_empty() {
+        return locale.get_text("empty_roster", &[]);
+    }
+
+    camper_roster
+        .get_campers()
+        .iter()
+        .enumerate()
+        .map(|(camper_idx, viewer)| {
+            locale.get_text(
+                "roster_camper",
+                &[
+                    ("{number}", (camper_idx + 1).to_string()),
+                    ("{camper}", viewer.get_name().clone()),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Opens the lobby with an empty roster, showing the title screen.
+pub fn open_lobby(lobby_rules: Res<LobbyRules>, locale: Res<Locale>, mut commands: Commands) {
+    let camper_roster = CamperRoster::default();
+    let lobby_countdown = LobbyCountdown::new(&lobby_rules);
+    let lobby_status_text =
+        get_lobby_status_text(&camper_roster, &lobby_rules, &lobby_countdown, &locale);
+    let lobby_roster_text = get_lobby_roster_text(&camper_roster, &locale);
+
+    commands
+        .spawn((
+            LobbyScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.1, 0.05)),
+        ))
+        .with_children(|lobby_screen| {
+            lobby_screen.spawn((
+                LobbyTitle,
+                Text::new(locale.get_text("lobby_title", &[])),
+                TextFont::from_font_size(64.0),
+            ));
+            lobby_screen.spawn((
+                Text::new(locale.get_text("lobby_invitation", &[])),
+                TextFont::from_font_size(28.0),
+            ));
+            lobby_screen.spawn((
+                LobbyStatus,
+                Text::new(lobby_status_text),
+                TextFont::from_font_size(24.0),
+            ));
+            lobby_screen.spawn((
+                LobbyRoster,
+                Text::new(lobby_roster_text),
+                TextFont::from_font_size(20.0),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+        });
+
+    commands.insert_resource(camper_roster);
+    commands.insert_resource(lobby_countdown);
+}
+
+/// Adds every viewer who clicks on the title screen to the roster.
+pub fn join_lobby(
+    mut viewer_clicks: MessageReader<ViewerClick>,
+    mut camper_roster: ResMut<CamperRoster>,
+) {
+    for viewer_click in viewer_clicks.read() {
+        let viewer = viewer_click.get_viewer().clone();
+        let viewer_name = viewer.get_name().clone();
+        if camper_roster.join(viewer) {
+            info!("join_lobby: {} joined the trip.", viewer_name);
+        }
+    }
+}
+
+/// Begins the trip once the countdown runs out, or as soon as the streamer
+/// says so, as long as enough campers have joined. The countdown starts
+/// over otherwise.
+pub fn count_down_lobby(
+    time: Res<Time>,
+    mut start_trip_reader: MessageReader<StartTrip>,
+    camper_roster: Res<CamperRoster>,
+    lobby_rules: Res<LobbyRules>,
+    mut lobby_countdown: ResMut<LobbyCountdown>,
+    mut campers_state: ResMut<NextState<CampersState>>,
+) {
+    let has_enough_campers = camper_roster.len() >= lobby_rules.get_min_campers();
+    if !start_trip_reader.is_empty() {
+        start_trip_reader.clear();
+        if has_enough_campers {
+            campers_state.set(CampersState::Alive);
+            return;
+        }
+    }
+
+    lobby_countdown.0.tick(time.delta());
+    if !lobby_countdown.0.is_finished() {
+        return;
+    }
+
+    if !has_enough_campers {
+        lobby_countdown.0.reset();
+        return;
+    }
+
+    campers_state.set(CampersState::Alive);
+}
+
+/// Keeps the roster and the time left in the lobby up to date on the
+/// title screen.
+pub fn update_lobby_screen(
+    camper_roster: Res<CamperRoster>,
+    lobby_rules: Res<LobbyRules>,
+    lobby_countdown: Res<LobbyCountdown>,
+    mut lobby_status: Single<&mut Text, (With<LobbyStatus>, Without<LobbyRoster>)>,
+    mut lobby_roster: Single<&mut Text, (With<LobbyRoster>, Without<LobbyStatus>)>,
+    locale: Res<Locale>,
+) {
+    let lobby_status_text =
+        get_lobby_status_text(&camper_roster, &lobby_rules, &lobby_countdown, &locale);
+    if lobby_status.0 != lobby_status_text {
+        lobby_status.0 = lobby_status_text;
+    }
+
+    if camper_roster.is_changed() {
+        lobby_roster.0 = get_lobby_roster_text(&camper_roster, &locale);
+    }
+}
+
+/// Takes the title screen off of the stream once the trip begins, closing
+/// the roster to anyone who did not join.
+pub fn close_lobby(
+    lobby_screens: Query<Entity, With<LobbyScreen>>,
+    mut camper_roster: ResMut<CamperRoster>,
+    mut commands: Commands,
+) {
+    for lobby_screen in &lobby_screens {
+        commands.entity(lobby_screen).despawn();
+    }
+
+    // A roster nobody joined is left open, since nobody could ever play
+    // otherwise.
+    if !camper_roster.is_empty() {
+        camper_roster.close();
+    }
+
+    commands.remove_resource::<LobbyCountdown>();
+}
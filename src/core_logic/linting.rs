@@ -0,0 +1,137 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::core_logic::{
+    progressing::{ObjectivesError, ObjectivesFile},
+    setting::{check_locations_of_interest, load_tiled_map_file},
+};
+
+/// Represents a mistake found in a campsite's map or objectives file
+/// that would break the game during a stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintProblem {
+    file: PathBuf,
+    location: String,
+    problem: String,
+}
+
+impl LintProblem {
+    pub fn new(file: PathBuf, location: String, problem: String) -> Self {
+        Self {
+            file,
+            location,
+            problem,
+        }
+    }
+
+    pub fn get_file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    /// Returns where in the file the problem is, such as a JSON path
+    /// or the name of a map layer.
+    pub fn get_location(&self) -> &String {
+        &self.location
+    }
+
+    pub fn get_problem(&self) -> &String {
+        &self.problem
+    }
+}
+
+impl From<ObjectivesError> for LintProblem {
+    fn from(objectives_error: ObjectivesError) -> Self {
+        Self::new(
+            objectives_error.get_file().clone(),
+            objectives_error.get_json_path().clone(),
+            objectives_error.get_problem().clone(),
+        )
+    }
+}
+
+impl fmt::Display for LintProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {}",
+            self.file.display(),
+            self.location,
+            self.problem
+        )
+    }
+}
+
+/// Returns every problem found in a Tiled map that the game would
+/// trip over when loading it.
+fn lint_map(map_path: &Path) -> Vec<LintProblem> {
+    let map_problem = |location: &str, problem: String| {
+        LintProblem::new(map_path.to_path_buf(), location.to_string(), problem)
+    };
+
+    let tiled_map = match load_tiled_map_file(map_path) {
+        Ok(tiled_map) => tiled_map,
+        Err(map_error) => return vec![map_problem(".", map_error.to_string())],
+    };
+
+    let mut map_problems: Vec<LintProblem> = check_locations_of_interest(&tiled_map)
+        .into_iter()
+        .map(|location_problem| map_problem("Locations of Interest", location_problem))
+        .collect();
+
+    let has_bus_layer = tiled_map.layers().any(|layer| layer.name == "Bus");
+    if !has_bus_layer {
+        map_problems.push(map_problem(
+            ".",
+            String::from("Could not find Bus layer, so the bus can never leave"),
+        ));
+    }
+
+    map_problems
+}
+
+/// Returns every problem found in an objectives file that the game would
+/// trip over when loading it, or once campers start completing objectives.
+fn lint_objectives(objectives_path: &Path) -> Vec<LintProblem> {
+    let objectives_file = match ObjectivesFile::parse_file(objectives_path) {
+        Ok(objectives_file) => objectives_file,
+        Err(objectives_error) => return vec![LintProblem::from(objectives_error)],
+    };
+
+    let mut objectives_problems: Vec<LintProblem> = objectives_file
+        .validate(objectives_path)
+        .into_iter()
+        .map(LintProblem::from)
+        .collect();
+
+    for (objective_idx, objective) in objectives_file.objectives.iter().enumerate() {
+        let objective_name = objective.get_name();
+        let has_scenario = objectives_file
+            .landmarks
+            .iter()
+            .flat_map(|landmark| landmark.scenarios.iter())
+            .any(|scenario| scenario.objective == *objective_name);
+        if !has_scenario {
+            objectives_problems.push(LintProblem::new(
+                objectives_path.to_path_buf(),
+                format!("objectives[{}]", objective_idx),
+                format!(
+                    "'{}' has no scenarios, so it can never be completed",
+                    objective_name
+                ),
+            ));
+        }
+    }
+
+    objectives_problems
+}
+
+/// Returns every problem found in a campsite's map and objectives file,
+/// or nothing if the campsite is ready to be streamed.
+pub fn lint_campsite(map_path: &Path, objectives_path: &Path) -> Vec<LintProblem> {
+    let mut campsite_problems = lint_map(map_path);
+    campsite_problems.extend(lint_objectives(objectives_path));
+
+    campsite_problems
+}
@@ -1,5 +1,13 @@
+pub mod choosing;
+pub mod clicking;
 pub mod interacting;
+pub mod joining;
+pub mod linting;
+pub mod phrasing;
 pub mod progressing;
+pub mod reserving;
+pub mod resolving;
+pub mod restarting;
 pub mod setting;
 pub mod traveling;
 
@@ -9,13 +17,45 @@ use bevy::prelude::*;
 use interacting::ViewerClick;
 
 use crate::core_logic::{
-    interacting::{ObjectiveAttempt, ScenarioAttempt},
+    choosing::{
+        ActiveScenario, ScenarioVotes, TieBreaker, VotingTime, close_scenario_voting,
+        collect_scenario_votes, hide_scenario_panel, present_scenario_to_campers,
+        show_scenario_panel, update_voting_display,
+    },
+    clicking::{
+        ClickLimiter, ClickLimits, ClickSources, read_click_sources, report_dropped_clicks,
+    },
+    interacting::{
+        ObjectiveAttempt, ScenarioAttempt, TileClicked, convert_viewer_click_to_tile_click,
+    },
+    joining::{
+        CamperRoster, LobbyRules, StartTrip, close_lobby, count_down_lobby, join_lobby, open_lobby,
+        update_lobby_screen,
+    },
+    phrasing::Locale,
     progressing::{
-        HungerBar, HungerBarTime, decrease_hunger_bar_over_time, determine_campers_state,
-        load_map_objectives, record_camper_contribution, spawn_hunger_bar,
+        CampsiteCleared, HungerBar, HungerBarTime, TripProgress, advance_trip, begin_trip,
+        complete_camper_objectives, decrease_hunger_bar_over_time, determine_campers_state,
+        load_map_objectives, record_camper_contribution, replenish_hunger_bar, spawn_hunger_bar,
+        unload_map_objectives,
+    },
+    reserving::{BusDoorQueue, TileReservations},
+    resolving::{
+        CamperRecords, GameRng, ScenarioChoiceMade, remember_camper_results,
+        resolve_scenario_choices,
+    },
+    restarting::{
+        FirstCampsite, clear_game_over_countdown, count_down_game_over, reload_first_campsite,
+        remember_first_campsite, reset_campers, start_game_over_countdown,
+    },
+    setting::{
+        ChangeMap, LoadMap, TileSize, center_camera_on_map, load_tiled_map, unload_current_map,
+    },
+    traveling::{
+        CamperArrived, continue_camper_journey, forget_camper_reservations, move_bus_to_exit,
+        move_camper_to_bus, move_camper_to_meadows, move_campers_along_path,
+        queue_campers_at_bus_door, start_bus_departure,
     },
-    setting::{ChangeMap, LoadMap, load_tiled_map, unload_current_map},
-    traveling::move_camper_to_meadows,
 };
 
 #[derive(States, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
@@ -78,6 +118,7 @@ pub struct CoreLogic {
     movement_time: MovementTime,
     game_over_time: GameOverTime,
     hunger_bar_decrease_time: HungerBarTime,
+    voting_time: VotingTime,
 }
 
 impl CoreLogic {
@@ -85,11 +126,13 @@ impl CoreLogic {
         movement_time: MovementTime,
         game_over_time: GameOverTime,
         hunger_bar_decrease_time: HungerBarTime,
+        voting_time: VotingTime,
     ) -> Self {
         Self {
             movement_time,
             game_over_time,
             hunger_bar_decrease_time,
+            voting_time,
         }
     }
 }
@@ -99,8 +142,13 @@ impl Plugin for CoreLogic {
         app.add_message::<ObjectiveAttempt>();
         app.add_message::<ScenarioAttempt>();
         app.add_message::<ViewerClick>();
+        app.add_message::<TileClicked>();
         app.add_message::<LoadMap>();
         app.add_message::<ChangeMap>();
+        app.add_message::<CamperArrived>();
+        app.add_message::<CampsiteCleared>();
+        app.add_message::<ScenarioChoiceMade>();
+        app.add_message::<StartTrip>();
 
         app.init_state::<CampersState>();
         app.insert_resource(self.movement_time.clone());
@@ -109,17 +157,169 @@ impl Plugin for CoreLogic {
         app.insert_resource(self.hunger_bar_decrease_time.clone());
         app.insert_resource(HungerBar::default());
         app.add_systems(Startup, spawn_hunger_bar);
-        app.add_systems(Update, decrease_hunger_bar_over_time);
+        // Campers only get hungrier, and can only starve, while on the trip.
+        app.add_systems(
+            Update,
+            (decrease_hunger_bar_over_time, determine_campers_state)
+                .chain()
+                .run_if(in_state(CampersState::Alive)),
+        );
+
+        // Viewers join the trip from a lobby before it begins.
+        app.init_resource::<LobbyRules>();
+        app.init_resource::<CamperRoster>();
+        app.add_systems(OnEnter(CampersState::Start), open_lobby);
+        app.add_systems(
+            Update,
+            (join_lobby, count_down_lobby, update_lobby_screen)
+                .chain()
+                .after(read_click_sources)
+                .run_if(in_state(CampersState::Start)),
+        );
+        app.add_systems(
+            OnExit(CampersState::Start),
+            (
+                close_lobby,
+                begin_trip.run_if(resource_exists::<TripProgress>),
+            ),
+        );
+
+        // Once the campers starve, the game counts down and then starts
+        // over from the first campsite, so that the stream never stops.
+        app.add_systems(
+            Update,
+            remember_first_campsite.run_if(not(resource_exists::<FirstCampsite>)),
+        );
+        app.add_systems(OnEnter(CampersState::Dead), start_game_over_countdown);
         app.add_systems(
             Update,
-            determine_campers_state.after(decrease_hunger_bar_over_time),
+            count_down_game_over.run_if(in_state(CampersState::Dead)),
+        );
+        app.add_systems(
+            OnExit(CampersState::Dead),
+            (
+                clear_game_over_countdown,
+                reset_campers,
+                reload_first_campsite,
+            ),
         );
 
+        app.init_resource::<TileSize>();
+        app.init_resource::<Locale>();
         app.add_systems(Update, (unload_current_map, load_tiled_map));
+        app.add_systems(Update, center_camera_on_map.after(load_tiled_map));
         app.add_systems(Update, load_map_objectives.after(load_tiled_map));
+        app.add_systems(
+            Update,
+            unload_map_objectives
+                .after(unload_current_map)
+                .before(load_map_objectives),
+        );
 
-        app.add_systems(Update, move_camper_to_meadows);
+        // Campers plan their paths around the spots other campers have
+        // reserved, and step out of the bus one at a time.
+        app.init_resource::<TileReservations>();
+        app.init_resource::<BusDoorQueue>();
+        app.add_systems(
+            Update,
+            (queue_campers_at_bus_door, move_camper_to_meadows).chain(),
+        );
+        app.add_systems(
+            Update,
+            forget_camper_reservations.before(move_campers_along_path),
+        );
+        // Campers start moving the frame after they appear, so that
+        // everyone sees where they started from first.
+        app.add_systems(
+            Update,
+            move_campers_along_path.before(move_camper_to_meadows),
+        );
+        app.add_systems(
+            Update,
+            (continue_camper_journey, move_camper_to_bus)
+                .chain()
+                .after(move_campers_along_path),
+        );
 
+        app.init_resource::<GameRng>();
+        app.init_resource::<CamperRecords>();
+        // Choices are resolved first, so that everything else hears how
+        // they turned out within the same frame.
+        app.add_systems(
+            Update,
+            (resolve_scenario_choices, remember_camper_results)
+                .chain()
+                .before(record_camper_contribution)
+                .before(replenish_hunger_bar)
+                .before(complete_camper_objectives)
+                .before(move_camper_to_bus),
+        );
         app.add_systems(Update, record_camper_contribution);
+        app.add_systems(
+            Update,
+            replenish_hunger_bar
+                .after(decrease_hunger_bar_over_time)
+                .before(determine_campers_state),
+        );
+        app.add_systems(Update, complete_camper_objectives);
+        // The bus leaves as soon as the last camper boards it.
+        app.add_systems(
+            Update,
+            (move_bus_to_exit, start_bus_departure)
+                .chain()
+                .after(continue_camper_journey)
+                .after(complete_camper_objectives),
+        );
+
+        // A trip is optional, so that single campsites can still be loaded
+        // on their own.
+        app.add_systems(
+            Update,
+            (
+                begin_trip
+                    .run_if(resource_added::<TripProgress>.and(not(in_state(CampersState::Start)))),
+                advance_trip
+                    .run_if(resource_exists::<TripProgress>)
+                    .after(move_bus_to_exit),
+            ),
+        );
+
+        app.init_resource::<ClickSources>();
+        app.init_resource::<ClickLimits>();
+        app.init_resource::<ClickLimiter>();
+        app.add_systems(Update, (read_click_sources, report_dropped_clicks).chain());
+        app.add_systems(
+            Update,
+            convert_viewer_click_to_tile_click.after(read_click_sources),
+        );
+
+        // Viewers vote on what campers in the meadows do by clicking on the
+        // choices shown along the bottom of the stream.
+        app.insert_resource(self.voting_time.clone());
+        app.init_resource::<TieBreaker>();
+        app.add_systems(
+            Update,
+            (
+                (
+                    collect_scenario_votes,
+                    close_scenario_voting.run_if(resource_exists::<ScenarioVotes>),
+                )
+                    .chain()
+                    .after(read_click_sources)
+                    .before(resolve_scenario_choices),
+                present_scenario_to_campers.after(move_camper_to_bus),
+                hide_scenario_panel
+                    .run_if(resource_removed::<ActiveScenario>)
+                    .after(close_scenario_voting)
+                    .before(show_scenario_panel),
+                show_scenario_panel
+                    .run_if(resource_exists_and_changed::<ActiveScenario>)
+                    .after(present_scenario_to_campers),
+                update_voting_display
+                    .run_if(resource_exists::<ScenarioVotes>)
+                    .after(close_scenario_voting)
+                    .after(show_scenario_panel),
+            ),
+        );
     }
 }
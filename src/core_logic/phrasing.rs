@@ -0,0 +1,387 @@
+use bevy::prelude::*;
+
+/// The placeholder in an achieved message that is replaced with the name
+/// of the camper who completed the objective.
+pub const CAMPER_PLACEHOLDER: &str = "{camper}";
+
+/// The placeholder in an achieved message that is replaced with the name
+/// of the objective completed.
+pub const OBJECTIVE_PLACEHOLDER: &str = "{objective}";
+
+/// The locale used when no other is chosen, or when something has not
+/// been translated.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Every piece of text the game shows in each locale, looked up by its key
+/// and then by locale. Text that has not been translated falls back to
+/// the default locale's.
+const TRANSLATIONS: [(&str, &str, &str); 39] = [
+    // How a contribution reads when an objective cannot be put into words
+    // on its own.
+    (
+        "completed_objective",
+        "en",
+        "{camper} completed '{objective}'!",
+    ),
+    (
+        "completed_objective",
+        "es",
+        "¡{camper} completó «{objective}»!",
+    ),
+    (
+        "completed_objective",
+        "de",
+        "{camper} hat „{objective}“ erledigt!",
+    ),
+    // Why a campsite's objectives, or the trip itself, could not be loaded.
+    (
+        "objectives_error",
+        "en",
+        "Could not load {file} at {json_path}: {problem}",
+    ),
+    (
+        "objectives_error",
+        "es",
+        "No se pudo cargar {file} en {json_path}: {problem}",
+    ),
+    (
+        "objectives_error",
+        "de",
+        "{file} konnte bei {json_path} nicht geladen werden: {problem}",
+    ),
+    // How many viewers voted for a choice.
+    ("one_vote", "en", "1 vote"),
+    ("one_vote", "es", "1 voto"),
+    ("one_vote", "de", "1 Stimme"),
+    ("many_votes", "en", "{count} votes"),
+    ("many_votes", "es", "{count} votos"),
+    ("many_votes", "de", "{count} Stimmen"),
+    // The title of the scenario panel, asking viewers to vote.
+    ("scenario_title", "en", "What would you like to do?"),
+    ("scenario_title", "es", "¿Qué les gustaría hacer?"),
+    ("scenario_title", "de", "Was möchtet ihr tun?"),
+    // The countdown to starting over once the campers starve.
+    (
+        "game_over_countdown",
+        "en",
+        "The campers starved!\nStarting over in {seconds} seconds...",
+    ),
+    (
+        "game_over_countdown",
+        "es",
+        "¡Los campistas murieron de hambre!\nEmpezando de nuevo en {seconds} segundos...",
+    ),
+    (
+        "game_over_countdown",
+        "de",
+        "Die Camper sind verhungert!\nNeustart in {seconds} Sekunden...",
+    ),
+    // When the trip begins, as told by the lobby.
+    (
+        "bus_leaving",
+        "en",
+        "The bus leaves in {seconds} seconds...",
+    ),
+    (
+        "bus_leaving",
+        "es",
+        "El autobús sale en {seconds} segundos...",
+    ),
+    (
+        "bus_leaving",
+        "de",
+        "Der Bus fährt in {seconds} Sekunden ab...",
+    ),
+    (
+        "camper_missing",
+        "en",
+        "Waiting for 1 more camper to join...",
+    ),
+    ("camper_missing", "es", "Esperando a 1 campista más..."),
+    ("camper_missing", "de", "Warte auf 1 weiteren Camper..."),
+    (
+        "campers_missing",
+        "en",
+        "Waiting for {count} more campers to join...",
+    ),
+    (
+        "campers_missing",
+        "es",
+        "Esperando a {count} campistas más...",
+    ),
+    (
+        "campers_missing",
+        "de",
+        "Warte auf {count} weitere Camper...",
+    ),
+    // The lobby's title screen, and the campers who joined from it.
+    ("lobby_title", "en", "Surviving the Trip"),
+    ("lobby_title", "es", "Sobreviviendo al Viaje"),
+    ("lobby_title", "de", "Überleben auf der Reise"),
+    ("lobby_invitation", "en", "Click anywhere to join the trip!"),
+    (
+        "lobby_invitation",
+        "es",
+        "¡Hagan clic en cualquier lugar para unirse al viaje!",
+    ),
+    (
+        "lobby_invitation",
+        "de",
+        "Klickt irgendwo, um bei der Reise mitzumachen!",
+    ),
+    ("empty_roster", "en", "Nobody has joined yet."),
+    ("empty_roster", "es", "Nadie se ha unido todavía."),
+    ("empty_roster", "de", "Noch ist niemand dabei."),
+    ("roster_camper", "en", "Camper {number}: {camper}"),
+    ("roster_camper", "es", "Campista {number}: {camper}"),
+    ("roster_camper", "de", "Camper {number}: {camper}"),
+];
+
+/// Represents the language that the game's text is shown in, such as
+/// 'en', 'es', or 'de'.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Locale(String);
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new(String::from(DEFAULT_LOCALE))
+    }
+}
+
+impl Locale {
+    pub fn new(locale_code: String) -> Self {
+        Self(locale_code.to_lowercase())
+    }
+
+    pub fn get_code(&self) -> &String {
+        &self.0
+    }
+
+    /// Returns whether this is the locale everything else falls back to.
+    pub fn is_default(&self) -> bool {
+        self.0 == DEFAULT_LOCALE
+    }
+
+    /// Returns the text for some key in this locale, with each of its
+    /// placeholders replaced by their value, falling back to the default
+    /// locale when the text has not been translated.
+    pub fn get_text(&self, text_key: &str, placeholder_values: &[(&str, String)]) -> String {
+        let find_text = |locale_code: &str| {
+            TRANSLATIONS
+                .iter()
+                .find(|(key, code, _)| *key == text_key && *code == locale_code)
+                .map(|(_, _, text)| *text)
+        };
+
+        let Some(text) = find_text(self.get_code()).or_else(|| find_text(DEFAULT_LOCALE)) else {
+            warn!("get_text: No text found for {}.", text_key);
+            return String::from(text_key);
+        };
+
+        placeholder_values
+            .iter()
+            .fold(String::from(text), |text, (placeholder, value)| {
+                text.replace(placeholder, value)
+            })
+    }
+}
+
+/// Verbs whose past tense does not follow the usual rules, paired with
+/// how they read once an objective is done.
+const IRREGULAR_VERBS: [(&str, &str); 48] = [
+    ("be", "was"),
+    ("bring", "brought"),
+    ("build", "built"),
+    ("buy", "bought"),
+    ("catch", "caught"),
+    ("choose", "chose"),
+    ("cut", "cut"),
+    ("dig", "dug"),
+    ("do", "did"),
+    ("draw", "drew"),
+    ("drink", "drank"),
+    ("drive", "drove"),
+    ("eat", "ate"),
+    ("feed", "fed"),
+    ("fight", "fought"),
+    ("find", "found"),
+    ("fly", "flew"),
+    ("get", "got"),
+    ("give", "gave"),
+    ("go", "went"),
+    ("grow", "grew"),
+    ("hang", "hung"),
+    ("hide", "hid"),
+    ("hold", "held"),
+    ("keep", "kept"),
+    ("lead", "led"),
+    ("leave", "left"),
+    ("light", "lit"),
+    ("make", "made"),
+    ("meet", "met"),
+    ("put", "put"),
+    ("read", "read"),
+    ("ride", "rode"),
+    ("run", "ran"),
+    ("see", "saw"),
+    // Seeking something only counts as done once it has been found.
+    ("seek", "found"),
+    ("set", "set"),
+    ("shoot", "shot"),
+    ("sleep", "slept"),
+    ("spend", "spent"),
+    ("stand", "stood"),
+    ("steal", "stole"),
+    ("swim", "swam"),
+    ("take", "took"),
+    ("teach", "taught"),
+    ("tell", "told"),
+    ("throw", "threw"),
+    ("win", "won"),
+];
+
+/// Returns whether the letter is a vowel for the purposes of spelling
+/// out a past tense verb.
+fn is_vowel(letter: char) -> bool {
+    matches!(letter, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Returns whether a verb is a single syllable ending in a
+/// consonant-vowel-consonant, such as 'trap', whose last letter is
+/// doubled before adding 'ed'.
+fn doubles_last_consonant(verb: &str) -> bool {
+    let letters: Vec<char> = verb.chars().collect();
+    let [.., before_vowel, vowel, last] = letters[..] else {
+        return false;
+    };
+
+    let num_vowel_groups = letters
+        .iter()
+        .enumerate()
+        .filter(|(letter_idx, letter)| {
+            is_vowel(**letter) && (*letter_idx == 0 || !is_vowel(letters[letter_idx - 1]))
+        })
+        .count();
+
+    num_vowel_groups == 1
+        && !is_vowel(before_vowel)
+        && is_vowel(vowel)
+        && !is_vowel(last)
+        && !matches!(last, 'w' | 'x' | 'y')
+}
+
+/// Returns the past tense of a lowercase verb.
+/// Example: 'build' -> 'built', 'gather' -> 'gathered'
+pub fn get_past_tense(verb: &str) -> String {
+    if let Some((_, past_tense)) = IRREGULAR_VERBS
+        .iter()
+        .find(|(irregular_verb, _)| *irregular_verb == verb)
+    {
+        return past_tense.to_string();
+    }
+
+    if verb.ends_with('e') {
+        return format!("{}d", verb);
+    }
+
+    if let Some(verb_stem) = verb.strip_suffix('y')
+        && verb_stem
+            .chars()
+            .last()
+            .is_some_and(|letter| !is_vowel(letter))
+    {
+        return format!("{}ied", verb_stem);
+    }
+
+    if doubles_last_consonant(verb) {
+        let last_letter = verb.chars().last().unwrap_or_default();
+        return format!("{}{}ed", verb, last_letter);
+    }
+
+    format!("{}ed", verb)
+}
+
+/// Converts an objective name into an achieved message template, or
+/// returns why it could not be.
+/// Example: 'Build a shelter.' -> '{camper} built a shelter!'
+pub fn try_get_achieved_message_template(objective_name: &str) -> Result<String, String> {
+    let objective_sentence = objective_name.trim().trim_end_matches(['.', '!']);
+    if objective_sentence.is_empty() {
+        return Err(String::from("An empty objective was given."));
+    }
+
+    let (objective_action, objective_phrase) = objective_sentence
+        .split_once(char::is_whitespace)
+        .unwrap_or((objective_sentence, ""));
+    if !objective_action.chars().all(char::is_alphabetic) {
+        return Err(format!("Could not find action in: {}", objective_sentence));
+    }
+
+    let past_tense_action = get_past_tense(&objective_action.to_lowercase());
+    let achieved_message = format!(
+        "{} {} {}",
+        CAMPER_PLACEHOLDER,
+        past_tense_action,
+        objective_phrase.trim()
+    );
+    Ok(format!("{}!", achieved_message.trim_end()))
+}
+
+/// Returns the achieved message template for an objective without one
+/// written out. Objectives in the default locale are put into words,
+/// while other locales, or objectives that cannot be put into words, name
+/// the objective outright.
+pub fn get_default_achieved_message(objective_name: &str, locale: &Locale) -> String {
+    if locale.is_default()
+        && let Ok(achieved_message) = try_get_achieved_message_template(objective_name)
+    {
+        return achieved_message;
+    }
+
+    // The placeholders are left in, to be filled in once completed.
+    locale.get_text("completed_objective", &[])
+}
+
+/// Returns why an achieved message template would not read properly,
+/// if it would not.
+pub fn check_achieved_message_template(achieved_message: &str) -> Result<(), String> {
+    let mut remaining_message = achieved_message;
+    while let Some(placeholder_start) = remaining_message.find('{') {
+        let placeholder = &remaining_message[placeholder_start..];
+        let placeholder_end = placeholder
+            .find('}')
+            .ok_or_else(|| format!("Unclosed placeholder in: {}", achieved_message))?;
+        let placeholder = &placeholder[..=placeholder_end];
+        if placeholder != CAMPER_PLACEHOLDER && placeholder != OBJECTIVE_PLACEHOLDER {
+            return Err(format!(
+                "Unknown placeholder {}, expected {} or {}",
+                placeholder, CAMPER_PLACEHOLDER, OBJECTIVE_PLACEHOLDER
+            ));
+        }
+
+        remaining_message = &remaining_message[placeholder_start + placeholder_end + 1..];
+    }
+
+    if !achieved_message.contains(CAMPER_PLACEHOLDER) {
+        return Err(format!(
+            "Missing {} placeholder, so nobody gets credit",
+            CAMPER_PLACEHOLDER
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns an achieved message template with the camper's name and
+/// objective filled in.
+/// Example: ('{camper} found food!', 'Player 1', 'Find food.') -> 'Player 1 found food!'
+pub fn fill_achieved_message(
+    achieved_message: &str,
+    camper_name: &str,
+    objective_name: &str,
+) -> String {
+    let objective_sentence = objective_name.trim().trim_end_matches(['.', '!']);
+    achieved_message
+        .replace(CAMPER_PLACEHOLDER, camper_name)
+        .replace(OBJECTIVE_PLACEHOLDER, objective_sentence)
+}
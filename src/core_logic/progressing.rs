@@ -1,12 +1,23 @@
-use std::{fs::File, io::BufReader, path::PathBuf, time::Duration};
+use std::{
+    fmt,
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use bevy::prelude::*;
-use serde_json::Value;
+use serde::Deserialize;
 
 use crate::core_logic::{
     CampersState,
     interacting::{ScenarioAttempt, ScenarioResult},
-    setting::LoadMap,
+    phrasing::{
+        Locale, check_achieved_message_template, fill_achieved_message,
+        get_default_achieved_message, try_get_achieved_message_template,
+    },
+    resolving::GameRng,
+    setting::{ChangeMap, LoadMap},
 };
 
 /// Represents the hunger of all campers in the game.
@@ -25,7 +36,8 @@ pub struct HungerBar {
 
 impl Default for HungerBar {
     fn default() -> Self {
-        let current_percentage = 0;
+        // Campers start every trip with a full stomach.
+        let current_percentage = 100;
         let percent_decrease = 1;
         Self {
             current_percentage,
@@ -52,12 +64,22 @@ impl HungerBar {
 
     /// Decreases the hunger bar by one chunk determined by the percentage decrease.
     pub fn decrease(&mut self) {
-        if self.percent_decrease > self.current_percentage {
-            self.current_percentage = 0;
-            return;
-        }
+        self.penalize(self.percent_decrease);
+    }
+
+    /// Fills the hunger bar all the way back up, like at the start of a trip.
+    pub fn refill(&mut self) {
+        self.current_percentage = 100;
+    }
+
+    /// Fills the hunger bar back up by some percentage, up to 100%.
+    pub fn restore(&mut self, percentage_restored: usize) {
+        self.current_percentage = (self.current_percentage + percentage_restored).min(100);
+    }
 
-        self.current_percentage -= self.percent_decrease;
+    /// Empties the hunger bar by some percentage, down to 0%.
+    pub fn penalize(&mut self, percentage_lost: usize) {
+        self.current_percentage = self.current_percentage.saturating_sub(percentage_lost);
     }
 }
 
@@ -105,48 +127,28 @@ pub struct ContributionsList {
     contributions: Vec<String>,
 }
 
-/// Converts an objective name into an achieved message.
-/// Example: 'Find food.' -> 'found food!'
-fn get_achieved_objective_msg(objective_name: &str) -> String {
-    let mut objective_message = objective_name.to_lowercase();
-    // 1. Remove the '.' at the end.
-    objective_message
-        .pop()
-        .expect("get_achieved_objective_msg: An empty string was given.");
-
-    // 2. Past tense the verb in the sentence.
-    // Example: 'find' -> 'found'
-    let mut objective_split_on_whitespace = objective_message.split_whitespace();
-    let objective_action = objective_split_on_whitespace
-        .next()
-        .expect("get_achieved_objective_msg: Could not find action.");
-    let past_tense_action = match objective_action {
-        "find" => "found",
-        "seek" => "found",
-        _ => panic!(
-            "get_achieved_objective_msg: Unrecognized action given: {}",
-            objective_action
-        ),
-    };
-
-    let objective_completed = objective_split_on_whitespace
-        .next()
-        .expect("get_achieved_objective_msg: Could not get what was achieved.");
-
-    format!("{} {}!", past_tense_action, objective_completed)
-}
-
 impl ContributionsList {
     pub fn contains(&self, contribution: &String) -> bool {
         self.contributions.contains(contribution)
     }
 
-    /// Converts an objective attempt into a recorded contribution.
-    pub fn record(&mut self, objective_attempt: &ScenarioAttempt) {
+    pub fn len(&self) -> usize {
+        self.contributions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contributions.is_empty()
+    }
+
+    /// Converts an objective attempt into a recorded contribution, worded
+    /// by the achieved message of the objective attempted.
+    pub fn record(&mut self, objective_attempt: &ScenarioAttempt, achieved_message: &str) {
         let camper_name = objective_attempt.get_camper_name();
-        let camper_objective_achieved =
-            get_achieved_objective_msg(objective_attempt.get_objective());
-        let contribution = format!("{} {}", camper_name, camper_objective_achieved);
+        let contribution = fill_achieved_message(
+            achieved_message,
+            camper_name,
+            objective_attempt.get_objective(),
+        );
         self.contributions.push(contribution);
     }
 }
@@ -154,6 +156,8 @@ impl ContributionsList {
 /// Records all successful attempts of an objective from the campers.
 pub fn record_camper_contribution(
     mut objective_attempts: MessageReader<ScenarioAttempt>,
+    camper_objectives: Query<&CamperObjective>,
+    locale: Res<Locale>,
     mut contributions_list: Single<&mut ContributionsList>,
 ) {
     for objective_attempt in objective_attempts.read() {
@@ -161,7 +165,32 @@ pub fn record_camper_contribution(
             continue;
         }
 
-        contributions_list.record(objective_attempt);
+        let achieved_message = camper_objectives
+            .iter()
+            .find(|camper_objective| camper_objective.label == *objective_attempt.get_objective())
+            .map(|camper_objective| camper_objective.get_achieved_message().clone())
+            .unwrap_or_else(|| {
+                get_default_achieved_message(objective_attempt.get_objective(), &locale)
+            });
+        contributions_list.record(objective_attempt, &achieved_message);
+    }
+}
+
+/// Fills the hunger bar back up when campers succeed at a choice, and
+/// empties it some when they fail, depending on the choice made.
+pub fn replenish_hunger_bar(
+    mut scenario_attempts: MessageReader<ScenarioAttempt>,
+    mut hunger_bar: ResMut<HungerBar>,
+) {
+    for scenario_attempt in scenario_attempts.read() {
+        let Some(scenario_choice) = scenario_attempt.get_choice() else {
+            continue;
+        };
+
+        match scenario_attempt.get_status() {
+            ScenarioResult::Success => hunger_bar.restore(scenario_choice.get_hunger_restored()),
+            ScenarioResult::Fail => hunger_bar.penalize(scenario_choice.get_hunger_lost()),
+        }
     }
 }
 
@@ -202,16 +231,63 @@ pub fn determine_campers_state(
 #[derive(Component)]
 pub struct CamperObjective {
     label: String,
+    achieved_message: String,
+    completed: bool,
 }
 
 impl CamperObjective {
     pub fn new(label: String) -> Self {
-        Self { label }
+        let achieved_message = get_default_achieved_message(&label, &Locale::default());
+        Self {
+            label,
+            achieved_message,
+            completed: false,
+        }
     }
 
     pub fn get_name(&self) -> String {
         self.label.clone()
     }
+
+    /// Sets how a camper's contribution reads once they complete this
+    /// objective, such as '{camper} built a shelter!'
+    pub fn set_achieved_message(&mut self, achieved_message: String) {
+        self.achieved_message = achieved_message;
+    }
+
+    pub fn get_achieved_message(&self) -> &String {
+        &self.achieved_message
+    }
+
+    pub fn complete(&mut self) {
+        self.completed = true;
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+}
+
+/// Represents the bus leaving a campsite after every objective was completed.
+#[derive(Message, Clone, Copy, Debug, Default)]
+pub struct CampsiteCleared;
+
+/// Marks objectives as completed once any camper succeeds at them.
+pub fn complete_camper_objectives(
+    mut scenario_attempts: MessageReader<ScenarioAttempt>,
+    mut camper_objectives: Query<&mut CamperObjective>,
+) {
+    for scenario_attempt in scenario_attempts.read() {
+        if scenario_attempt.get_status() == ScenarioResult::Fail {
+            continue;
+        }
+
+        for mut camper_objective in &mut camper_objectives {
+            if camper_objective.get_name() == *scenario_attempt.get_objective() {
+                camper_objective.complete();
+            }
+        }
+    }
 }
 
 /// A resource holding the location of where to load objective files.
@@ -228,39 +304,6 @@ impl ObjectivesDirectory {
     }
 }
 
-/// Returns a BufferedReader from the loaded Objectives file, or panics if
-/// there's a problem with the file.
-fn load_objective_file(
-    objectives_directory: &ObjectivesDirectory,
-    loaded_map_name: String,
-) -> BufReader<File> {
-    let objective_file_name = loaded_map_name + "_objectives.json";
-    let mut objective_file_path = PathBuf::from(objectives_directory.get_path());
-    objective_file_path.push(objective_file_name);
-
-    let loaded_objective_file =
-        File::open(objective_file_path).expect("load_objective_file: Could not load file.");
-    BufReader::new(loaded_objective_file)
-}
-
-/// Spawns into the game the list of objectives from the provided objectives json file
-/// already loaded.
-fn spawn_map_objectives(objectives_json: &Value, commands: &mut Commands) {
-    let objective_names: Vec<String> = objectives_json["objectives"]
-        .as_array()
-        .expect("spawn_map_objectives: Could not find objectives array in json.")
-        .iter()
-        .map(|objective_json_value| objective_json_value.as_str().unwrap().to_string())
-        .collect();
-
-    for objective_name in objective_names {
-        let parsed_objective = CamperObjective::new(objective_name);
-        commands.spawn(parsed_objective);
-    }
-
-    commands.spawn(ContributionsList::default());
-}
-
 /// Represents some setting with a series of scenarios that can take place there.
 #[derive(Component)]
 pub struct Landmark {
@@ -293,9 +336,14 @@ impl Landmark {
     pub fn get_scenario(&self, scenario_num: usize) -> &LandmarkScenario {
         &self.scenarios[scenario_num]
     }
+
+    pub fn get_scenarios(&self) -> &Vec<LandmarkScenario> {
+        &self.scenarios
+    }
 }
 
 /// Represents some situation with a series of choices to do.
+#[derive(Clone, Debug)]
 pub struct LandmarkScenario {
     objective_type: String,
     description: String,
@@ -327,22 +375,35 @@ impl LandmarkScenario {
     pub fn get_choice(&self, choice_num: usize) -> &ScenarioChoice {
         &self.choices[choice_num]
     }
+
+    pub fn get_choices(&self) -> &Vec<ScenarioChoice> {
+        &self.choices
+    }
 }
 
+/// The percent chance of a choice succeeding when none is given.
+pub const DEFAULT_SUCCESS_CHANCE: usize = 50;
+
 /// Represents an action that a camper/viewer can do that yields some result.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ScenarioChoice {
     description: String,
 
     success_description: String,
     failure_description: String,
+
+    hunger_restored: usize,
+    hunger_lost: usize,
+
+    success_chance: usize,
 }
 
 impl ScenarioChoice {
     pub fn new(description: String) -> Self {
         Self {
             description,
-            success_description: String::new(),
-            failure_description: String::new(),
+            success_chance: DEFAULT_SUCCESS_CHANCE,
+            ..default()
         }
     }
 
@@ -365,94 +426,459 @@ impl ScenarioChoice {
     pub fn get_failure_result(&self) -> String {
         self.failure_description.clone()
     }
+
+    /// Sets how much of the hunger bar is filled back up on success.
+    pub fn set_hunger_restored(&mut self, hunger_restored: usize) {
+        self.hunger_restored = hunger_restored;
+    }
+
+    pub fn get_hunger_restored(&self) -> usize {
+        self.hunger_restored
+    }
+
+    /// Sets how much of the hunger bar is lost on failure.
+    pub fn set_hunger_lost(&mut self, hunger_lost: usize) {
+        self.hunger_lost = hunger_lost;
+    }
+
+    pub fn get_hunger_lost(&self) -> usize {
+        self.hunger_lost
+    }
+
+    /// Sets the percent chance of this choice succeeding before anything
+    /// else about the campers is taken into account.
+    pub fn set_success_chance(&mut self, success_chance: usize) {
+        self.success_chance = success_chance;
+    }
+
+    pub fn get_success_chance(&self) -> usize {
+        self.success_chance
+    }
+}
+
+/// The version of the objectives file format understood by the game.
+pub const OBJECTIVES_SCHEMA_VERSION: u32 = 1;
+
+/// The contents of an objectives file, listing what campers can do at
+/// a campsite.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectivesFile {
+    pub schema_version: u32,
+    /// The language the file is written in, which is English when not given.
+    #[serde(default)]
+    pub locale: Option<String>,
+    pub objectives: Vec<ObjectiveDef>,
+    pub landmarks: Vec<LandmarkDef>,
+}
+
+/// An objective as written in an objectives file, either just its name
+/// or its name along with how a contribution to it reads.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ObjectiveDef {
+    Name(String),
+    Described(DescribedObjectiveDef),
+}
+
+/// An objective as written in an objectives file with its own wording
+/// for contributions, such as '{camper} pitched the tents!'
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DescribedObjectiveDef {
+    pub name: String,
+    pub achieved_message: Option<String>,
+}
+
+impl ObjectiveDef {
+    pub fn get_name(&self) -> &String {
+        match self {
+            ObjectiveDef::Name(name) => name,
+            ObjectiveDef::Described(described_objective) => &described_objective.name,
+        }
+    }
+
+    /// Returns the written achieved message of this objective, if any.
+    pub fn get_achieved_message(&self) -> Option<&String> {
+        match self {
+            ObjectiveDef::Name(_) => None,
+            ObjectiveDef::Described(described_objective) => {
+                described_objective.achieved_message.as_ref()
+            }
+        }
+    }
+}
+
+/// A landmark as written in an objectives file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LandmarkDef {
+    pub name: String,
+    pub landmark_description: String,
+    pub scenarios: Vec<ScenarioDef>,
+}
+
+/// A scenario as written in an objectives file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioDef {
+    pub objective: String,
+    pub scenario_description: String,
+    pub choices: Vec<ChoiceDef>,
+}
+
+/// A choice as written in an objectives file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ChoiceDef {
+    pub choice_description: String,
+    pub results: ChoiceResultsDef,
+    #[serde(default)]
+    pub hunger_restored: usize,
+    #[serde(default)]
+    pub hunger_lost: usize,
+    #[serde(default = "get_default_success_chance")]
+    pub success_chance: usize,
 }
 
-/// Returns a Scenario Choice parsed from a JSON object.
-fn parse_choice(scenario_choice_entry: &Value) -> ScenarioChoice {
-    let choice_description = scenario_choice_entry["choice_description"]
-        .as_str()
-        .expect(
-            "parse_choice: Could not find choice_description field for the scenario in the json.",
+/// Returns the chance of a choice succeeding when an objectives file
+/// does not say.
+fn get_default_success_chance() -> usize {
+    DEFAULT_SUCCESS_CHANCE
+}
+
+/// What happens after a choice, as written in an objectives file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ChoiceResultsDef {
+    pub success: String,
+    pub failure: String,
+}
+
+/// Represents something wrong with an objectives file, pointing out
+/// where in the file the problem is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectivesError {
+    file: PathBuf,
+    json_path: String,
+    problem: String,
+}
+
+impl ObjectivesError {
+    pub fn new(file: PathBuf, json_path: String, problem: String) -> Self {
+        Self {
+            file,
+            json_path,
+            problem,
+        }
+    }
+
+    pub fn get_file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    /// Returns where in the file the problem is, such as
+    /// `landmarks[0].scenarios[1].choices`, or `.` for the whole file.
+    pub fn get_json_path(&self) -> &String {
+        &self.json_path
+    }
+
+    pub fn get_problem(&self) -> &String {
+        &self.problem
+    }
+}
+
+impl fmt::Display for ObjectivesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Could not load {} at {}: {}",
+            self.file.display(),
+            self.json_path,
+            self.problem
         )
-        .to_string();
-    let mut parsed_objective_choice = ScenarioChoice::new(choice_description);
-
-    let success_description = scenario_choice_entry["results"]["success"]
-        .as_str()
-        .expect("parse_choice: Could not find success field in json.")
-        .to_string();
-    parsed_objective_choice.set_success(success_description);
-    let failure_description = scenario_choice_entry["results"]["failure"]
-        .as_str()
-        .expect("parse_choice: Could not find failure field in json.")
-        .to_string();
-    parsed_objective_choice.set_failure(failure_description);
-
-    parsed_objective_choice
-}
-
-/// Returns a Landmark Scenario parsed from a JSON object.
-fn parse_scenario(scenario_entry: &Value) -> LandmarkScenario {
-    let objective_type = scenario_entry["objective"]
-        .as_str()
-        .expect("parse_scenario: Could not find objective field for scenario in json.")
-        .to_string();
-    let scenario_description = scenario_entry["scenario_description"]
-        .as_str()
-        .expect("parse_scenario: Could not find description field for scenario in json.")
-        .to_string();
-    let mut parsed_landmark_scenario = LandmarkScenario::new(objective_type, scenario_description);
-
-    let choice_entries = scenario_entry["choices"]
-        .as_array()
-        .expect("parse_scenario: Could not find choices in objectives json.");
-    for choice_entry in choice_entries {
-        let parsed_choice = parse_choice(choice_entry);
-        parsed_landmark_scenario.add_choice(parsed_choice);
-    }
-
-    parsed_landmark_scenario
-}
-
-/// Returns a Landmark parsed from a JSON object.
-fn parse_landmark(landmark_json_entry: &Value) -> Landmark {
-    let landmark_name = landmark_json_entry["name"]
-        .as_str()
-        .expect("parse_landmark: Could not find name for landmark in json.")
-        .to_string();
-    let landmark_description = landmark_json_entry["landmark_description"]
-        .as_str()
-        .expect("parse_landmark: Could not find description for landmark in json.")
-        .to_string();
-    let mut parsed_landmark = Landmark::new(landmark_name, landmark_description);
-
-    let scenario_entries = landmark_json_entry["scenarios"]
-        .as_array()
-        .expect("parse_landmark: Could not find scenarios in objectives json.");
-    for scenario_entry in scenario_entries {
-        let parsed_scenario = parse_scenario(scenario_entry);
-        parsed_landmark.add_scenario(parsed_scenario);
-    }
-
-    parsed_landmark
-}
-
-/// Spawns into the game the list of landmarks from the provided objectives json file
-/// already loaded.
-fn spawn_landmarks(objectives_json: &Value, commands: &mut Commands) {
-    let landmark_entries = objectives_json["landmarks"]
-        .as_array()
-        .expect("spawn_landmarks: Could not find list of landmarks in objectives json.");
-    for landmark_entry in landmark_entries {
-        let parsed_landmark = parse_landmark(landmark_entry);
-        commands.spawn(parsed_landmark);
     }
 }
 
+impl ObjectivesFile {
+    /// Returns the language the file is written in.
+    pub fn get_locale(&self) -> Locale {
+        self.locale.clone().map(Locale::new).unwrap_or_default()
+    }
+
+    /// Returns an Objectives File loaded from the given path, or what is
+    /// wrong with it.
+    pub fn from_file(objectives_file_path: &Path) -> Result<Self, ObjectivesError> {
+        let parsed_objectives_file = Self::parse_file(objectives_file_path)?;
+        if let Some(objectives_error) = parsed_objectives_file
+            .validate(objectives_file_path)
+            .into_iter()
+            .next()
+        {
+            return Err(objectives_error);
+        }
+
+        Ok(parsed_objectives_file)
+    }
+
+    /// Returns an Objectives File read from the given path without checking
+    /// whether it makes sense to the game, or why it could not be read.
+    pub fn parse_file(objectives_file_path: &Path) -> Result<Self, ObjectivesError> {
+        let objectives_error = |json_path: String, problem: String| {
+            ObjectivesError::new(objectives_file_path.to_path_buf(), json_path, problem)
+        };
+
+        let objectives_file = File::open(objectives_file_path)
+            .map_err(|file_error| objectives_error(String::from("."), file_error.to_string()))?;
+        let mut objectives_json =
+            serde_json::Deserializer::from_reader(BufReader::new(objectives_file));
+        serde_path_to_error::deserialize(&mut objectives_json).map_err(|parse_error| {
+            objectives_error(
+                parse_error.path().to_string(),
+                parse_error.inner().to_string(),
+            )
+        })
+    }
+
+    /// Returns every problem found in a well-formed Objectives File that
+    /// would not make sense to the game.
+    pub fn validate(&self, objectives_file_path: &Path) -> Vec<ObjectivesError> {
+        let objectives_error = |json_path: String, problem: String| {
+            ObjectivesError::new(objectives_file_path.to_path_buf(), json_path, problem)
+        };
+
+        let mut objectives_errors = Vec::new();
+        if self.schema_version != OBJECTIVES_SCHEMA_VERSION {
+            objectives_errors.push(objectives_error(
+                String::from("schema_version"),
+                format!(
+                    "Unsupported schema version {}, expected {}",
+                    self.schema_version, OBJECTIVES_SCHEMA_VERSION
+                ),
+            ));
+        }
+
+        for (objective_idx, objective) in self.objectives.iter().enumerate() {
+            let message_problem = match objective.get_achieved_message() {
+                Some(achieved_message) => check_achieved_message_template(achieved_message)
+                    .err()
+                    .map(|problem| {
+                        (
+                            format!("objectives[{}].achieved_message", objective_idx),
+                            problem,
+                        )
+                    }),
+                // Only objectives in the default locale are put into words
+                // on their own, so the rest always read as completed.
+                None if !self.get_locale().is_default() => None,
+                None => try_get_achieved_message_template(objective.get_name())
+                    .err()
+                    .map(|problem| {
+                        (
+                            format!("objectives[{}]", objective_idx),
+                            format!(
+                                "'{}' cannot be turned into a contribution: {}",
+                                objective.get_name(),
+                                problem
+                            ),
+                        )
+                    }),
+            };
+
+            if let Some((json_path, problem)) = message_problem {
+                objectives_errors.push(objectives_error(json_path, problem));
+            }
+        }
+
+        for (landmark_idx, landmark) in self.landmarks.iter().enumerate() {
+            for (scenario_idx, scenario) in landmark.scenarios.iter().enumerate() {
+                let is_objective = self
+                    .objectives
+                    .iter()
+                    .any(|objective| *objective.get_name() == scenario.objective);
+                if !is_objective {
+                    objectives_errors.push(objectives_error(
+                        format!(
+                            "landmarks[{}].scenarios[{}].objective",
+                            landmark_idx, scenario_idx
+                        ),
+                        format!("'{}' is not one of the objectives", scenario.objective),
+                    ));
+                }
+
+                for (choice_idx, choice) in scenario.choices.iter().enumerate() {
+                    if choice.success_chance > 100 {
+                        objectives_errors.push(objectives_error(
+                            format!(
+                                "landmarks[{}].scenarios[{}].choices[{}].success_chance",
+                                landmark_idx, scenario_idx, choice_idx
+                            ),
+                            format!(
+                                "A success chance of {}% is more than 100%",
+                                choice.success_chance
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        objectives_errors
+    }
+}
+
+impl From<&ChoiceDef> for ScenarioChoice {
+    fn from(choice_def: &ChoiceDef) -> Self {
+        let mut scenario_choice = ScenarioChoice::new(choice_def.choice_description.clone());
+        scenario_choice.set_success(choice_def.results.success.clone());
+        scenario_choice.set_failure(choice_def.results.failure.clone());
+        scenario_choice.set_hunger_restored(choice_def.hunger_restored);
+        scenario_choice.set_hunger_lost(choice_def.hunger_lost);
+        scenario_choice.set_success_chance(choice_def.success_chance);
+
+        scenario_choice
+    }
+}
+
+impl From<&ScenarioDef> for LandmarkScenario {
+    fn from(scenario_def: &ScenarioDef) -> Self {
+        let mut landmark_scenario = LandmarkScenario::new(
+            scenario_def.objective.clone(),
+            scenario_def.scenario_description.clone(),
+        );
+        for choice_def in &scenario_def.choices {
+            landmark_scenario.add_choice(ScenarioChoice::from(choice_def));
+        }
+
+        landmark_scenario
+    }
+}
+
+impl From<&LandmarkDef> for Landmark {
+    fn from(landmark_def: &LandmarkDef) -> Self {
+        let mut landmark = Landmark::new(
+            landmark_def.name.clone(),
+            landmark_def.landmark_description.clone(),
+        );
+        for scenario_def in &landmark_def.scenarios {
+            landmark.add_scenario(LandmarkScenario::from(scenario_def));
+        }
+
+        landmark
+    }
+}
+
+/// Shows on screen why the objectives for the current campsite, or the
+/// trip itself, could not be loaded, so that the stream keeps going while
+/// it gets fixed.
+#[derive(Component, Debug)]
+pub struct ObjectivesErrorMessage(ObjectivesError);
+
+impl ObjectivesErrorMessage {
+    pub fn get_error(&self) -> &ObjectivesError {
+        &self.0
+    }
+}
+
+/// Returns what shows an objectives error on screen in the given locale,
+/// so that the stream keeps going while it gets fixed.
+pub fn get_objectives_error_message(
+    objectives_error: ObjectivesError,
+    locale: &Locale,
+) -> impl Bundle {
+    let objectives_error_text = locale.get_text(
+        "objectives_error",
+        &[
+            ("{file}", objectives_error.get_file().display().to_string()),
+            ("{json_path}", objectives_error.get_json_path().clone()),
+            ("{problem}", objectives_error.get_problem().clone()),
+        ],
+    );
+
+    (
+        Text::new(objectives_error_text),
+        TextColor(Color::srgb(1.0, 0.3, 0.3)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(8.0),
+            ..default()
+        },
+        ObjectivesErrorMessage(objectives_error),
+    )
+}
+
+/// Spawns into the game the objectives, landmarks and contributions list
+/// of an Objectives File.
+fn spawn_map_objectives(objectives_file: &ObjectivesFile, commands: &mut Commands) {
+    let objectives_locale = objectives_file.get_locale();
+    for objective_def in &objectives_file.objectives {
+        let objective_name = objective_def.get_name().clone();
+        let achieved_message = objective_def
+            .get_achieved_message()
+            .cloned()
+            .unwrap_or_else(|| get_default_achieved_message(&objective_name, &objectives_locale));
+
+        let mut camper_objective = CamperObjective::new(objective_name);
+        camper_objective.set_achieved_message(achieved_message);
+
+        commands.spawn(camper_objective);
+    }
+
+    for landmark_def in &objectives_file.landmarks {
+        commands.spawn(Landmark::from(landmark_def));
+    }
+
+    commands.spawn(ContributionsList::default());
+}
+
+/// Returns the name of a map's objectives file written for the given
+/// locale, such as `campsite_1_objectives.json` for the default locale,
+/// or `campsite_1_objectives.es.json` for Spanish.
+pub fn get_objectives_file_name(map_name: &str, locale: &Locale) -> String {
+    if locale.is_default() {
+        return format!("{}_objectives.json", map_name);
+    }
+
+    format!("{}_objectives.{}.json", map_name, locale.get_code())
+}
+
+/// Returns where the objectives file of a map is for the given locale,
+/// falling back to the default locale's file when the map has not been
+/// translated.
+pub fn get_objectives_file_path(
+    objectives_directory: &ObjectivesDirectory,
+    map_name: &str,
+    locale: &Locale,
+) -> PathBuf {
+    let default_file_path = objectives_directory
+        .get_path()
+        .join(get_objectives_file_name(map_name, &Locale::default()));
+    let localized_file_path = objectives_directory
+        .get_path()
+        .join(get_objectives_file_name(map_name, locale));
+    if !locale.is_default() && !localized_file_path.exists() {
+        warn!(
+            "get_objectives_file_path: No '{}' objectives for {}, so {} is used instead.",
+            locale.get_code(),
+            map_name,
+            default_file_path.display()
+        );
+        return default_file_path;
+    }
+
+    localized_file_path
+}
+
 /// Spawns a series of Objectives for the camper based on the currently loaded map.
+///
+/// A campsite of the trip that names its own objectives file uses that
+/// file instead of the one found in the Objectives Directory.
 pub fn load_map_objectives(
     mut loaded_map_reader: MessageReader<LoadMap>,
     objectives_directory: Res<ObjectivesDirectory>,
+    locale: Res<Locale>,
+    trip_progress: Option<Res<TripProgress>>,
     mut commands: Commands,
 ) {
     if loaded_map_reader.is_empty() {
@@ -460,12 +886,313 @@ pub fn load_map_objectives(
     }
 
     let loaded_map = loaded_map_reader.read().next().unwrap();
-    let loaded_map_name = loaded_map.get_name();
+    let map_name = loaded_map.get_name();
+    let campsite_objectives_path = trip_progress
+        .as_ref()
+        .and_then(|trip_progress| trip_progress.get_current_campsite())
+        .filter(|current_campsite| current_campsite.get_name() == map_name)
+        .and_then(|current_campsite| current_campsite.get_objectives_path())
+        .cloned();
+    let objective_file_path = campsite_objectives_path
+        .unwrap_or_else(|| get_objectives_file_path(&objectives_directory, &map_name, &locale));
+
+    match ObjectivesFile::from_file(&objective_file_path) {
+        Ok(objectives_file) => spawn_map_objectives(&objectives_file, &mut commands),
+        Err(objectives_error) => {
+            error!("load_map_objectives: {}", objectives_error);
+            commands.spawn(get_objectives_error_message(objectives_error, &locale));
+            // Contributions are still recorded while the campsite is
+            // skipped, so that nothing viewers do goes missing.
+            commands.spawn(ContributionsList::default());
+        }
+    }
+}
+
+/// Anything spawned from a campsite's objectives file.
+type CampsiteObjectivesEntity = Or<(
+    With<CamperObjective>,
+    With<Landmark>,
+    With<ContributionsList>,
+    With<ObjectivesErrorMessage>,
+)>;
+
+/// Despawns the objectives, landmarks and contributions of the current
+/// campsite before the next one is loaded.
+pub fn unload_map_objectives(
+    mut loaded_map_reader: MessageReader<LoadMap>,
+    campsite_entities: Query<Entity, CampsiteObjectivesEntity>,
+    mut commands: Commands,
+) {
+    if loaded_map_reader.is_empty() {
+        return;
+    }
 
-    let objectives_file = load_objective_file(&objectives_directory, loaded_map_name);
-    let objectives_json: Value = serde_json::from_reader(objectives_file)
-        .expect("load_map_objectives: Could not read from objectives file");
+    loaded_map_reader.clear();
+    for campsite_entity in &campsite_entities {
+        commands.entity(campsite_entity).despawn();
+    }
+}
 
-    spawn_map_objectives(&objectives_json, &mut commands);
-    spawn_landmarks(&objectives_json, &mut commands);
+/// A campsite visited during a trip, along with which campsites could
+/// come after it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TripCampsite {
+    map_path: PathBuf,
+    objectives_path: Option<PathBuf>,
+    next_campsites: Option<Vec<usize>>,
+}
+
+impl TripCampsite {
+    pub fn get_map_path(&self) -> &PathBuf {
+        &self.map_path
+    }
+
+    /// Returns the objectives file the trip names for this campsite, or
+    /// None if it comes from the Objectives Directory like any other map.
+    pub fn get_objectives_path(&self) -> Option<&PathBuf> {
+        self.objectives_path.as_ref()
+    }
+
+    /// Returns the name of the campsite's map without its extension.
+    pub fn get_name(&self) -> String {
+        self.map_path
+            .file_stem()
+            .expect("get_name: Campsite map does not have a name.")
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+/// Represents the campsites visited one after another to survive the trip.
+///
+/// A trip file looks like the following, where each map is relative to the
+/// trip file, and "next" lists the maps that can follow a campsite:
+/// {
+///   "campsites": [
+///     { "map": "../maps/campsite_1.tmx", "next": ["../maps/campsite_2.tmx"] },
+///     {
+///       "map": "../maps/campsite_2.tmx",
+///       "objectives": "../objectives/campsite_2_objectives.json",
+///       "next": []
+///     }
+///   ]
+/// }
+///
+/// Campsites without "next" are followed by the next campsite listed, and
+/// campsites with an empty "next" end the trip. Each campsite's objectives
+/// are loaded from the Objectives Directory like any other map, unless the
+/// campsite names its own "objectives" file, also relative to the trip file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trip {
+    campsites: Vec<TripCampsite>,
+}
+
+/// The contents of a trip file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TripFile {
+    pub campsites: Vec<TripCampsiteDef>,
+}
+
+/// A campsite as written in a trip file.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TripCampsiteDef {
+    pub map: String,
+    #[serde(default)]
+    pub objectives: Option<String>,
+    #[serde(default)]
+    pub next: Option<Vec<String>>,
+}
+
+impl TripFile {
+    /// Returns a Trip File read from the given path, or why it could not
+    /// be read.
+    pub fn parse_file(trip_file_path: &Path) -> Result<Self, ObjectivesError> {
+        let trip_error = |json_path: String, problem: String| {
+            ObjectivesError::new(trip_file_path.to_path_buf(), json_path, problem)
+        };
+
+        let trip_file = File::open(trip_file_path)
+            .map_err(|file_error| trip_error(String::from("."), file_error.to_string()))?;
+        let mut trip_json = serde_json::Deserializer::from_reader(BufReader::new(trip_file));
+        serde_path_to_error::deserialize(&mut trip_json).map_err(|parse_error| {
+            trip_error(
+                parse_error.path().to_string(),
+                parse_error.inner().to_string(),
+            )
+        })
+    }
+
+    /// Returns the Trip described by the file, with every map and
+    /// objectives file found relative to the trip directory, or the first
+    /// campsite that comes next without being part of the trip.
+    pub fn to_trip(&self, trip_file_path: &Path) -> Result<Trip, ObjectivesError> {
+        let trip_directory = trip_file_path.parent().unwrap_or(Path::new(""));
+
+        let mut campsites = Vec::new();
+        for (campsite_num, campsite_def) in self.campsites.iter().enumerate() {
+            let next_campsites = match &campsite_def.next {
+                Some(next_map_names) => {
+                    Some(self.find_campsites(next_map_names, campsite_num, trip_file_path)?)
+                }
+                None => None,
+            };
+
+            campsites.push(TripCampsite {
+                map_path: trip_directory.join(&campsite_def.map),
+                objectives_path: campsite_def
+                    .objectives
+                    .as_ref()
+                    .map(|objectives_path| trip_directory.join(objectives_path)),
+                next_campsites,
+            });
+        }
+
+        Ok(Trip { campsites })
+    }
+
+    /// Returns where each of the next maps of some campsite is listed in
+    /// the trip, or which one is not listed at all.
+    fn find_campsites(
+        &self,
+        next_map_names: &[String],
+        campsite_num: usize,
+        trip_file_path: &Path,
+    ) -> Result<Vec<usize>, ObjectivesError> {
+        let mut next_campsites = Vec::new();
+        for (next_num, next_map_name) in next_map_names.iter().enumerate() {
+            let next_campsite = self
+                .campsites
+                .iter()
+                .position(|campsite_def| campsite_def.map == *next_map_name)
+                .ok_or_else(|| {
+                    ObjectivesError::new(
+                        trip_file_path.to_path_buf(),
+                        format!("campsites[{}].next[{}]", campsite_num, next_num),
+                        format!("Unknown next campsite {}", next_map_name),
+                    )
+                })?;
+            next_campsites.push(next_campsite);
+        }
+
+        Ok(next_campsites)
+    }
+}
+
+impl Trip {
+    /// Returns a Trip loaded from a trip json file, or what is wrong with it.
+    pub fn from_file(trip_file_path: &Path) -> Result<Self, ObjectivesError> {
+        TripFile::parse_file(trip_file_path)?.to_trip(trip_file_path)
+    }
+
+    pub fn get_campsite(&self, campsite_num: usize) -> Option<&TripCampsite> {
+        self.campsites.get(campsite_num)
+    }
+
+    /// Returns which campsite comes after some campsite, picking one at
+    /// random when the trip branches, or None if the trip ends there.
+    pub fn get_next_campsite(&self, campsite_num: usize, game_rng: &mut GameRng) -> Option<usize> {
+        let Some(next_campsites) = &self.campsites.get(campsite_num)?.next_campsites else {
+            let next_campsite_num = campsite_num + 1;
+            return (next_campsite_num < self.campsites.len()).then_some(next_campsite_num);
+        };
+
+        if next_campsites.is_empty() {
+            return None;
+        }
+
+        let next_campsite_idx = game_rng.random_range(0..next_campsites.len());
+        Some(next_campsites[next_campsite_idx])
+    }
+}
+
+/// Keeps track of where the campers are in their trip, and how well
+/// they have done so far.
+#[derive(Resource)]
+pub struct TripProgress {
+    trip: Trip,
+    current_campsite: Option<usize>,
+    campsites_survived: usize,
+    total_contributions: usize,
+}
+
+impl TripProgress {
+    pub fn new(trip: Trip) -> Self {
+        Self {
+            trip,
+            current_campsite: None,
+            campsites_survived: 0,
+            total_contributions: 0,
+        }
+    }
+
+    /// Returns the campsite the campers are at, or None if the trip
+    /// has not begun yet or is already over.
+    pub fn get_current_campsite(&self) -> Option<&TripCampsite> {
+        self.trip.get_campsite(self.current_campsite?)
+    }
+
+    pub fn get_campsites_survived(&self) -> usize {
+        self.campsites_survived
+    }
+
+    pub fn get_total_contributions(&self) -> usize {
+        self.total_contributions
+    }
+
+    /// Takes the campers back to the first campsite of the trip, forgetting
+    /// how well they did along the way.
+    pub fn restart(&mut self) {
+        self.current_campsite = self.trip.get_campsite(0).map(|_first_campsite| 0);
+        self.campsites_survived = 0;
+        self.total_contributions = 0;
+    }
+
+    /// Returns whether the campers made it past the last campsite.
+    pub fn is_finished(&self) -> bool {
+        self.current_campsite.is_none() && self.campsites_survived > 0
+    }
+}
+
+/// Loads the first campsite of the trip once one is given.
+pub fn begin_trip(
+    mut trip_progress: ResMut<TripProgress>,
+    mut change_map_broadcaster: MessageWriter<ChangeMap>,
+) {
+    let Some(first_campsite) = trip_progress.trip.get_campsite(0) else {
+        return;
+    };
+
+    change_map_broadcaster.write(ChangeMap::new(first_campsite.get_map_path().clone()));
+    trip_progress.current_campsite = Some(0);
+}
+
+/// Records how the campers did at a cleared campsite, then moves them
+/// on to the next campsite of the trip, if there is one.
+pub fn advance_trip(
+    mut campsite_cleared_reader: MessageReader<CampsiteCleared>,
+    mut trip_progress: ResMut<TripProgress>,
+    contributions_list: Query<&ContributionsList>,
+    mut game_rng: ResMut<GameRng>,
+    mut change_map_broadcaster: MessageWriter<ChangeMap>,
+) {
+    for _campsite_cleared in campsite_cleared_reader.read() {
+        let Some(cleared_campsite) = trip_progress.current_campsite else {
+            continue;
+        };
+
+        trip_progress.campsites_survived += 1;
+        trip_progress.total_contributions += contributions_list
+            .iter()
+            .map(|contributions| contributions.len())
+            .sum::<usize>();
+
+        trip_progress.current_campsite = trip_progress
+            .trip
+            .get_next_campsite(cleared_campsite, &mut game_rng);
+        if let Some(next_campsite) = trip_progress.get_current_campsite() {
+            change_map_broadcaster.write(ChangeMap::new(next_campsite.get_map_path().clone()));
+        }
+    }
 }
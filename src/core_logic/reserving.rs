@@ -0,0 +1,142 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::core_logic::{interacting::ViewerIdentity, setting::LogicalCoordinates};
+
+/// Represents which camper gets to stand on which spot at each step of
+/// time, so that campers plan their paths around each other instead of
+/// piling up on the same tiles.
+///
+/// Campers move in lockstep, where a step passes once every camper has
+/// finished moving to their next spot.
+#[derive(Resource, Debug, Default)]
+pub struct TileReservations {
+    current_step: usize,
+    reservations: HashMap<(LogicalCoordinates, usize), ViewerIdentity>,
+}
+
+impl TileReservations {
+    pub fn get_current_step(&self) -> usize {
+        self.current_step
+    }
+
+    /// Moves on to the next step of time, forgetting every spot reserved
+    /// for steps that have already passed.
+    pub fn start_next_step(&mut self) {
+        self.current_step += 1;
+
+        let current_step = self.current_step;
+        self.reservations
+            .retain(|(_, step), _| *step >= current_step);
+    }
+
+    /// Returns the viewer whose camper will be standing on some spot at
+    /// some step, or None if nobody will be there.
+    pub fn get_reserver(
+        &self,
+        location: &LogicalCoordinates,
+        step: usize,
+    ) -> Option<&ViewerIdentity> {
+        self.reservations.get(&(*location, step))
+    }
+
+    pub fn is_reserved(&self, location: &LogicalCoordinates, step: usize) -> bool {
+        self.reservations.contains_key(&(*location, step))
+    }
+
+    /// Returns whether a camper other than the viewer's will be standing on
+    /// some spot at some step.
+    pub fn is_reserved_by_other(
+        &self,
+        location: &LogicalCoordinates,
+        step: usize,
+        viewer: &ViewerIdentity,
+    ) -> bool {
+        self.get_reserver(location, step)
+            .is_some_and(|reserver| reserver != viewer)
+    }
+
+    /// Reserves each spot along a path for a viewer, starting from the
+    /// current step, where each spot is stood on for one step.
+    ///
+    /// Spots already reserved by someone else are left to them.
+    pub fn reserve_path(&mut self, viewer: &ViewerIdentity, locations: &[LogicalCoordinates]) {
+        for (step_offset, location) in locations.iter().enumerate() {
+            self.reservations
+                .entry((*location, self.current_step + step_offset))
+                .or_insert_with(|| viewer.clone());
+        }
+    }
+
+    /// Forgets every reserved spot, starting the steps over.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Represents a viewer waiting inside of the bus for their camper to step
+/// out and attempt an objective.
+#[derive(Clone, Debug)]
+pub struct QueuedCamper {
+    viewer: ViewerIdentity,
+    objective: String,
+}
+
+impl QueuedCamper {
+    pub fn new(viewer: ViewerIdentity, objective: String) -> Self {
+        Self { viewer, objective }
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+
+    pub fn get_objective(&self) -> &String {
+        &self.objective
+    }
+}
+
+/// Represents the campers lined up inside of the bus, who step out of its
+/// door one at a time in the order they attempted an objective.
+#[derive(Resource, Debug, Default)]
+pub struct BusDoorQueue {
+    campers: VecDeque<QueuedCamper>,
+}
+
+impl BusDoorQueue {
+    /// Lines a camper up at the end of the queue, returning whether they
+    /// were not already waiting in it.
+    pub fn join(&mut self, queued_camper: QueuedCamper) -> bool {
+        if self.contains(queued_camper.get_viewer()) {
+            return false;
+        }
+
+        self.campers.push_back(queued_camper);
+        true
+    }
+
+    pub fn contains(&self, viewer: &ViewerIdentity) -> bool {
+        self.campers
+            .iter()
+            .any(|queued_camper| queued_camper.get_viewer() == viewer)
+    }
+
+    /// Returns the camper at the front of the queue, letting them out of
+    /// it, or None if nobody is waiting.
+    pub fn pop_next(&mut self) -> Option<QueuedCamper> {
+        self.campers.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.campers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.campers.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.campers.clear();
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::core_logic::{
+    interacting::{ScenarioAttempt, ScenarioResult, ViewerIdentity},
+    progressing::{HungerBar, ScenarioChoice},
+};
+
+/// The hunger bar percentage at which campers are neither helped nor
+/// hindered by how hungry they are.
+const STEADY_HUNGER_PERCENTAGE: isize = 50;
+/// How many points of hunger above or below steady it takes to change
+/// the chance of success by one percent.
+const HUNGER_PER_PERCENT_CHANCE: isize = 5;
+/// How much each vote for a choice, past the first, helps it succeed.
+const PERCENT_CHANCE_PER_EXTRA_VOTE: isize = 5;
+/// The most that votes can help a choice succeed.
+const MAX_PERCENT_CHANCE_FROM_VOTES: isize = 20;
+/// How much each past success over failure, or failure over success,
+/// changes a camper's chance of succeeding again.
+const PERCENT_CHANCE_PER_PAST_RESULT: isize = 5;
+/// The most that a camper's past results can change their chance of success.
+const MAX_PERCENT_CHANCE_FROM_PAST_RESULTS: isize = 15;
+
+/// The source of randomness for anything left to chance in the game,
+/// which can be seeded so that the same outcomes happen every time.
+#[derive(Resource)]
+pub struct GameRng(StdRng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::from_os_rng())
+    }
+}
+
+impl GameRng {
+    /// Returns a Game RNG that always produces the same outcomes for
+    /// the same seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+
+    /// Returns a random number within the given range.
+    pub fn random_range(&mut self, range: std::ops::Range<usize>) -> usize {
+        self.0.random_range(range)
+    }
+}
+
+/// Represents how a camper has done in their scenarios so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CamperRecord {
+    successes: usize,
+    failures: usize,
+}
+
+impl CamperRecord {
+    pub fn new(successes: usize, failures: usize) -> Self {
+        Self {
+            successes,
+            failures,
+        }
+    }
+
+    pub fn get_successes(&self) -> usize {
+        self.successes
+    }
+
+    pub fn get_failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Records the result of another scenario attempted by the camper.
+    pub fn record(&mut self, scenario_result: ScenarioResult) {
+        match scenario_result {
+            ScenarioResult::Success => self.successes += 1,
+            ScenarioResult::Fail => self.failures += 1,
+        }
+    }
+}
+
+/// The past results of every camper throughout the game.
+#[derive(Resource, Default)]
+pub struct CamperRecords(HashMap<ViewerIdentity, CamperRecord>);
+
+impl CamperRecords {
+    /// Returns how the viewer's camper has done so far, which is nothing
+    /// for viewers who have not attempted a scenario yet.
+    pub fn get_record(&self, viewer: &ViewerIdentity) -> CamperRecord {
+        self.0.get(viewer).copied().unwrap_or_default()
+    }
+
+    pub fn set_record(&mut self, viewer: ViewerIdentity, camper_record: CamperRecord) {
+        self.0.insert(viewer, camper_record);
+    }
+}
+
+/// Represents a viewer's camper going through with a choice in a scenario,
+/// whose outcome has yet to be decided.
+#[derive(Message, Clone, Debug)]
+pub struct ScenarioChoiceMade {
+    viewer: ViewerIdentity,
+    objective: String,
+    choice: ScenarioChoice,
+    num_votes: usize,
+}
+
+impl ScenarioChoiceMade {
+    pub fn new(
+        viewer: ViewerIdentity,
+        objective: String,
+        choice: ScenarioChoice,
+        num_votes: usize,
+    ) -> Self {
+        Self {
+            viewer,
+            objective,
+            choice,
+            num_votes,
+        }
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+
+    pub fn get_objective(&self) -> &String {
+        &self.objective
+    }
+
+    pub fn get_choice(&self) -> &ScenarioChoice {
+        &self.choice
+    }
+
+    /// Returns how many viewers voted for the choice.
+    pub fn get_num_votes(&self) -> usize {
+        self.num_votes
+    }
+}
+
+/// Returns the percent chance that a choice succeeds, starting from the
+/// choice's own chance, then adjusted by how hungry the campers are, how
+/// many viewers voted for it, and how the camper has done so far.
+pub fn get_success_chance(
+    scenario_choice: &ScenarioChoice,
+    hunger_percentage: usize,
+    num_votes: usize,
+    camper_record: &CamperRecord,
+) -> usize {
+    let hunger_modifier =
+        (hunger_percentage as isize - STEADY_HUNGER_PERCENTAGE) / HUNGER_PER_PERCENT_CHANCE;
+
+    let num_extra_votes = num_votes.saturating_sub(1) as isize;
+    let votes_modifier =
+        (num_extra_votes * PERCENT_CHANCE_PER_EXTRA_VOTE).min(MAX_PERCENT_CHANCE_FROM_VOTES);
+
+    let net_successes = camper_record.successes as isize - camper_record.failures as isize;
+    let past_results_modifier = (net_successes * PERCENT_CHANCE_PER_PAST_RESULT).clamp(
+        -MAX_PERCENT_CHANCE_FROM_PAST_RESULTS,
+        MAX_PERCENT_CHANCE_FROM_PAST_RESULTS,
+    );
+
+    let success_chance = scenario_choice.get_success_chance() as isize
+        + hunger_modifier
+        + votes_modifier
+        + past_results_modifier;
+
+    success_chance.clamp(0, 100) as usize
+}
+
+/// Decides whether each choice made by campers succeeds or fails, letting
+/// everyone know how their scenario turned out.
+pub fn resolve_scenario_choices(
+    mut scenario_choices_made: MessageReader<ScenarioChoiceMade>,
+    hunger_bar: Res<HungerBar>,
+    camper_records: Res<CamperRecords>,
+    mut game_rng: ResMut<GameRng>,
+    mut scenario_attempt_broadcaster: MessageWriter<ScenarioAttempt>,
+) {
+    for scenario_choice_made in scenario_choices_made.read() {
+        let success_chance = get_success_chance(
+            scenario_choice_made.get_choice(),
+            hunger_bar.get_current_percentage(),
+            scenario_choice_made.get_num_votes(),
+            &camper_records.get_record(scenario_choice_made.get_viewer()),
+        );
+
+        let scenario_result = if game_rng.random_range(0..100) < success_chance {
+            ScenarioResult::Success
+        } else {
+            ScenarioResult::Fail
+        };
+
+        let mut scenario_attempt = ScenarioAttempt::new(
+            scenario_choice_made.get_viewer().clone(),
+            scenario_choice_made.get_objective().clone(),
+            scenario_result,
+        );
+        scenario_attempt.set_choice(scenario_choice_made.get_choice().clone());
+        scenario_attempt_broadcaster.write(scenario_attempt);
+    }
+}
+
+/// Keeps track of how every camper has done in their scenarios.
+pub fn remember_camper_results(
+    mut scenario_attempts: MessageReader<ScenarioAttempt>,
+    mut camper_records: ResMut<CamperRecords>,
+) {
+    for scenario_attempt in scenario_attempts.read() {
+        let viewer = scenario_attempt.get_viewer();
+        let mut camper_record = camper_records.get_record(viewer);
+        camper_record.record(scenario_attempt.get_status());
+        camper_records.set_record(viewer.clone(), camper_record);
+    }
+}
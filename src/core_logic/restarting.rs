@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::core_logic::{
+    CampersState, GameOverTime, GameOverTimer, TimerType,
+    choosing::{ActiveScenario, ScenarioVotes},
+    interacting::CamperInformation,
+    phrasing::Locale,
+    progressing::{HungerBar, TripProgress},
+    reserving::{BusDoorQueue, TileReservations},
+    resolving::CamperRecords,
+    setting::ChangeMap,
+};
+
+/// The campsite the campers go back to once the game restarts, which is
+/// the first campsite ever loaded.
+#[derive(Resource, Clone, Debug)]
+pub struct FirstCampsite(PathBuf);
+
+impl FirstCampsite {
+    pub fn new(map_path: PathBuf) -> Self {
+        Self(map_path)
+    }
+
+    pub fn get_map_path(&self) -> &PathBuf {
+        &self.0
+    }
+}
+
+/// Marks the text counting down to when the game restarts.
+#[derive(Component)]
+pub struct GameOverCountdown;
+
+/// Returns what the countdown says with some number of seconds left.
+fn get_countdown_text(seconds_left: u64, locale: &Locale) -> String {
+    locale.get_text(
+        "game_over_countdown",
+        &[("{seconds}", seconds_left.to_string())],
+    )
+}
+
+/// Returns how many whole seconds are left on a timer, rounding up so that
+/// the countdown only reaches 0 once the timer is finished.
+fn get_seconds_left(timer: &Timer) -> u64 {
+    timer.remaining().as_secs_f32().ceil() as u64
+}
+
+/// Remembers the first campsite loaded, so that the game knows where to
+/// start over from.
+pub fn remember_first_campsite(
+    mut change_map_reader: MessageReader<ChangeMap>,
+    mut commands: Commands,
+) {
+    let Some(first_change_map) = change_map_reader.read().next() else {
+        return;
+    };
+
+    let map_path = first_change_map.get_map().source.clone();
+    commands.insert_resource(FirstCampsite::new(map_path));
+}
+
+/// Starts counting down to a restart once the campers have died.
+pub fn start_game_over_countdown(
+    game_over_time: Res<GameOverTime>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    let game_over_timer = GameOverTimer::new(&game_over_time);
+    let seconds_left = get_seconds_left(game_over_timer.get_timer());
+
+    commands.spawn((
+        GameOverCountdown,
+        game_over_timer,
+        TimerType::GameOver,
+        Text::new(get_countdown_text(seconds_left, &locale)),
+        TextFont::from_font_size(48.0),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            top: Val::Percent(40.0),
+            ..default()
+        },
+    ));
+}
+
+/// Counts down to the restart, bringing the campers back to life once
+/// the time is up.
+pub fn count_down_game_over(
+    time: Res<Time>,
+    game_over_countdown: Single<(&mut GameOverTimer, &mut Text), With<GameOverCountdown>>,
+    locale: Res<Locale>,
+    mut campers_state: ResMut<NextState<CampersState>>,
+) {
+    let (mut game_over_timer, mut countdown_text) = game_over_countdown.into_inner();
+    game_over_timer.get_timer_mut().tick(time.delta());
+
+    let seconds_left = get_seconds_left(game_over_timer.get_timer());
+    let latest_countdown_text = get_countdown_text(seconds_left, &locale);
+    if countdown_text.0 != latest_countdown_text {
+        countdown_text.0 = latest_countdown_text;
+    }
+
+    if game_over_timer.get_timer().is_finished() {
+        campers_state.set(CampersState::Alive);
+    }
+}
+
+/// Takes the countdown off of the stream once the game restarts.
+pub fn clear_game_over_countdown(
+    game_over_countdowns: Query<Entity, With<GameOverCountdown>>,
+    mut commands: Commands,
+) {
+    for game_over_countdown in &game_over_countdowns {
+        commands.entity(game_over_countdown).despawn();
+    }
+}
+
+/// Fills the hunger bar back up and sends every camper home, forgetting
+/// how they did, where they were headed, and any scenario they were
+/// still in.
+pub fn reset_campers(
+    mut hunger_bar: ResMut<HungerBar>,
+    mut camper_records: ResMut<CamperRecords>,
+    mut tile_reservations: ResMut<TileReservations>,
+    mut bus_door_queue: ResMut<BusDoorQueue>,
+    campers: Query<Entity, With<CamperInformation>>,
+    mut commands: Commands,
+) {
+    hunger_bar.refill();
+    *camper_records = CamperRecords::default();
+    tile_reservations.clear();
+    bus_door_queue.clear();
+
+    for camper in &campers {
+        commands.entity(camper).despawn();
+    }
+
+    commands.remove_resource::<ActiveScenario>();
+    commands.remove_resource::<ScenarioVotes>();
+}
+
+/// Sends the campers back to the first campsite, starting the trip over
+/// if they were on one.
+///
+/// Loading the campsite again replaces its objectives, landmarks and
+/// contributions list with fresh ones.
+pub fn reload_first_campsite(
+    trip_progress: Option<ResMut<TripProgress>>,
+    first_campsite: Option<Res<FirstCampsite>>,
+    mut change_map_broadcaster: MessageWriter<ChangeMap>,
+) {
+    if let Some(mut trip_progress) = trip_progress {
+        trip_progress.restart();
+        if let Some(first_trip_campsite) = trip_progress.get_current_campsite() {
+            let map_path = first_trip_campsite.get_map_path().clone();
+            change_map_broadcaster.write(ChangeMap::new(map_path));
+            return;
+        }
+    }
+
+    let Some(first_campsite) = first_campsite else {
+        return;
+    };
+
+    change_map_broadcaster.write(ChangeMap::new(first_campsite.get_map_path().clone()));
+}
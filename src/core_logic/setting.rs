@@ -1,9 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use bevy::prelude::*;
 use tiled::{Loader, Map, Object};
 
-use crate::core_logic::traveling::{Graph, MeadowEntrance, OutsideOfBus};
+use crate::core_logic::traveling::{Bus, BusExit, Graph, MeadowEntrance, OutsideOfBus};
 
 pub const WALLS_OFFSET: usize = 2;
 
@@ -24,6 +24,10 @@ impl TileSize {
         Self { size, scale }
     }
 
+    pub fn set_size(&mut self, desired_size: usize) {
+        self.size = desired_size;
+    }
+
     pub fn set_scale(&mut self, desired_scale: usize) {
         self.scale = desired_scale;
     }
@@ -70,7 +74,7 @@ impl WorldTileDimensions {
     }
 }
 
-#[derive(Message, Component, Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 pub struct LogicalCoordinates {
     x: usize,
     y: usize,
@@ -95,7 +99,7 @@ impl LogicalCoordinates {
     }
 
     pub fn to_1d(&self, world_tile_dimensions: &WorldTileDimensions) -> usize {
-        (world_tile_dimensions.get_height() * self.get_y()) + self.get_x()
+        (world_tile_dimensions.get_width() * self.get_y()) + self.get_x()
     }
 }
 
@@ -119,12 +123,16 @@ impl Tile {
 #[derive(Message)]
 pub struct ChangeMap(Map);
 
+/// Returns a Tiled map loaded from the given path, or why it could not be loaded.
+pub fn load_tiled_map_file(map_to_load: &Path) -> Result<Map, tiled::Error> {
+    let mut loader = Loader::new();
+    loader.load_tmx_map(map_to_load)
+}
+
 impl ChangeMap {
     pub fn new(map_to_load: PathBuf) -> Self {
-        let mut loader = Loader::new();
-        let loaded_map = loader
-            .load_tmx_map(map_to_load)
-            .expect("Could not load the desired Tiled map.");
+        let loaded_map =
+            load_tiled_map_file(&map_to_load).expect("Could not load the desired Tiled map.");
         Self(loaded_map)
     }
 
@@ -263,6 +271,51 @@ fn flip_physical_y_coordinate(
     tile_map_height as f32 - tile_y as f32
 }
 
+/// Returns the LogicalCoordinates of the tile found at some physical position in
+/// pixels, or None if the position is outside of the map.
+///
+/// This undoes the flip from flip_physical_y_coordinate, since Tiled has its y
+/// coordinates going down, while Bevy has it going up.
+pub fn get_logical_from_physical(
+    physical_position: Vec2,
+    tile_size: usize,
+    map_size: &WorldTileDimensions,
+) -> Option<LogicalCoordinates> {
+    let tile_size = tile_size as f32;
+    let tile_map_height = map_size.get_height() as f32 * tile_size;
+
+    // Tiles are centered on their physical position, so rounding
+    // finds whichever tile the position falls within.
+    let logical_x = (physical_position.x / tile_size).round();
+    let logical_y = ((tile_map_height - physical_position.y) / tile_size).round();
+
+    let within_width = (0.0..map_size.get_width() as f32).contains(&logical_x);
+    let within_height = (0.0..map_size.get_height() as f32).contains(&logical_y);
+    if !within_width || !within_height {
+        return None;
+    }
+
+    Some(LogicalCoordinates::new(
+        logical_x as usize,
+        logical_y as usize,
+        0,
+    ))
+}
+
+/// Returns the physical position in pixels of the tile found at some
+/// LogicalCoordinates, with its y coordinate flipped for Bevy.
+pub fn get_physical_from_logical(
+    logical_coordinates: &LogicalCoordinates,
+    tile_size: usize,
+    map_size: &WorldTileDimensions,
+) -> Vec2 {
+    let tile_map_height = map_size.get_height() * tile_size;
+
+    let physical_x = logical_coordinates.get_x() * tile_size;
+    let flipped_y = tile_map_height - (logical_coordinates.get_y() * tile_size);
+    Vec2::new(physical_x as f32, flipped_y as f32)
+}
+
 /// Returns the Tile's Sprite based on its location as a SpriteBundle.
 fn get_tile_sprite_from_tiled(
     tile_logical_coordinates: &LogicalCoordinates,
@@ -293,6 +346,7 @@ pub fn unload_current_map(
     mut load_map_broadcaster: MessageWriter<LoadMap>,
     rendered_tiles: Query<Entity, With<LogicalCoordinates>>,
     map_size: Query<Entity, With<WorldTileDimensions>>,
+    traversal_graph: Query<Entity, With<Graph>>,
     mut commands: Commands,
 ) {
     for change_map_event in change_map_reader.read() {
@@ -300,6 +354,10 @@ pub fn unload_current_map(
             commands.entity(rendered_tile_entity).despawn();
         }
 
+        for traversal_graph_entity in &traversal_graph {
+            commands.entity(traversal_graph_entity).despawn();
+        }
+
         if let Ok(map_size_entity) = map_size.single() {
             commands.entity(map_size_entity).despawn();
         }
@@ -360,25 +418,89 @@ fn get_physical_coordinates(
     Transform::from_xyz(x as f32, flipped_y, z as f32)
 }
 
-/// Spawns Locations of Interest (objects) found from the Tiled map.
-fn spawn_locations_of_interest(tiled_map: &Map, commands: &mut Commands) {
+/// The names of every Location of Interest each map needs exactly one of.
+pub const LOCATIONS_OF_INTEREST: [&str; 3] = ["Outside of Bus", "Meadows", "Bus Exit"];
+
+/// Returns whether some spot lies past the edges of the Tiled map.
+fn is_outside_of_map(logical_coordinates: &LogicalCoordinates, tiled_map: &Map) -> bool {
+    logical_coordinates.get_x() >= tiled_map.width as usize
+        || logical_coordinates.get_y() >= tiled_map.height as usize
+}
+
+/// Returns every problem with the Locations of Interest (objects) found
+/// in the Tiled map, such as missing or unknown locations.
+pub fn check_locations_of_interest(tiled_map: &Map) -> Vec<String> {
     let locations_of_interest_layer_num = 1;
-    let is_not_object_layer = tiled_map
+    let Some(tile_object_layer) = tiled_map
         .get_layer(locations_of_interest_layer_num)
-        .is_none_or(|layer| {
-            layer.as_object_layer().is_none() || layer.name != "Locations of Interest"
-        });
-    if is_not_object_layer {
-        panic!("spawn_locations_of_interest: Could not find Locations of Interest layer");
+        .filter(|layer| layer.name == "Locations of Interest")
+        .and_then(|layer| layer.as_object_layer())
+    else {
+        return vec![String::from(
+            "Could not find Locations of Interest layer as the second layer",
+        )];
+    };
+
+    let mut location_problems = Vec::new();
+    let mut locations_found = Vec::new();
+    for object in tile_object_layer.objects() {
+        if !LOCATIONS_OF_INTEREST.contains(&object.name.as_str()) {
+            location_problems.push(format!("Unknown object found: {}", object.name));
+            continue;
+        }
+
+        let object_logical_position = get_logical_from_tiled_object(&object, tiled_map.tile_width);
+        if is_outside_of_map(&object_logical_position, tiled_map) {
+            location_problems.push(format!("{} is outside of the map", object.name));
+        }
+
+        locations_found.push(object.name.clone());
+    }
+
+    for location_name in LOCATIONS_OF_INTEREST {
+        let num_found = locations_found
+            .iter()
+            .filter(|location_found| *location_found == location_name)
+            .count();
+        match num_found {
+            0 => location_problems.push(format!("Could not find {}", location_name)),
+            1 => {}
+            _ => location_problems.push(format!(
+                "Found {} of {}, expected only one",
+                num_found, location_name
+            )),
+        }
     }
 
-    let tile_object_layer = tiled_map
+    location_problems
+}
+
+/// Spawns Locations of Interest (objects) found from the Tiled map.
+///
+/// Mistakes in the locations are caught by campsite-lint before a stream,
+/// so any found here are only warned about, skipping the objects at fault.
+fn spawn_locations_of_interest(tiled_map: &Map, commands: &mut Commands) {
+    for location_problem in check_locations_of_interest(tiled_map) {
+        warn!("spawn_locations_of_interest: {}.", location_problem);
+    }
+
+    let locations_of_interest_layer_num = 1;
+    let Some(tile_object_layer) = tiled_map
         .get_layer(locations_of_interest_layer_num)
-        .unwrap()
-        .as_object_layer()
-        .unwrap();
+        .filter(|layer| layer.name == "Locations of Interest")
+        .and_then(|layer| layer.as_object_layer())
+    else {
+        return;
+    };
+
+    let mut locations_spawned = Vec::new();
     for object in tile_object_layer.objects() {
         let object_logical_position = get_logical_from_tiled_object(&object, tiled_map.tile_width);
+        let is_duplicate = locations_spawned.contains(&object.name);
+        if is_duplicate || is_outside_of_map(&object_logical_position, tiled_map) {
+            continue;
+        }
+
         let object_physical_position =
             get_physical_coordinates(&object_logical_position, tiled_map);
         match object.name.as_str() {
@@ -396,25 +518,25 @@ fn spawn_locations_of_interest(tiled_map: &Map, commands: &mut Commands) {
                     MeadowEntrance,
                 ));
             }
-            _ => panic!(
-                "spawn_locations_of_interest: Unknown object found: {}",
-                object.name
-            ),
+            "Bus Exit" => {
+                commands.spawn((object_logical_position, object_physical_position, BusExit));
+            }
+            _ => continue,
         }
+
+        locations_spawned.push(object.name.clone());
     }
 }
 
-/// Returns a Traversal Graph parsed from a list of Tile Bundles.
-fn get_traversal_graph(
-    tile_bundles: &[TileBundle],
-    tiled_map_dimensions: &WorldTileDimensions,
-) -> Graph {
-    let tile_logical_coordinates: Vec<LogicalCoordinates> = tile_bundles
-        .iter()
-        .map(|tile_bundle| tile_bundle.logical_coordinates)
-        .collect();
+/// Returns how many times larger the map should be drawn to fill the window.
+fn get_map_scale(tiled_map: &Map, window: Option<&Window>) -> usize {
+    let Some(window) = window else {
+        return 1;
+    };
 
-    Graph::from_tiles(&tile_logical_coordinates, tiled_map_dimensions)
+    let map_physical_width = (tiled_map.width * tiled_map.tile_width) as f32;
+    let map_scale = (window.width() / map_physical_width).round() as usize;
+    map_scale.max(1)
 }
 
 /// Converts a Tiled map into a series of Tile locations and their sprites.
@@ -422,6 +544,8 @@ pub fn load_tiled_map(
     mut load_tiled_map_reader: MessageReader<LoadMap>,
     asset_server: Res<AssetServer>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut tile_size: ResMut<TileSize>,
+    window: Query<&Window>,
     mut commands: Commands,
 ) {
     let mut bevy_sprite_loader = BevySpriteLoader::new(&asset_server, &mut texture_atlas_layouts);
@@ -435,18 +559,30 @@ pub fn load_tiled_map(
 
         let tiled_map_dimensions = WorldTileDimensions::new(map_width, map_height, map_depth);
 
+        // ASSUMPTION: The tile height and tile width are the same.
+        tile_size.set_size(loaded_tile_map.tile_width as usize);
+        tile_size.set_scale(get_map_scale(loaded_tile_map, window.single().ok()));
+
         let tile_bundles = get_bevy_tiles(
             loaded_tile_map,
             &tiled_map_dimensions,
             &mut bevy_sprite_loader,
         );
 
-        let traversal_graph = get_traversal_graph(&tile_bundles, &tiled_map_dimensions);
+        let traversal_graph = Graph::from_tiles(loaded_tile_map, &tiled_map_dimensions);
         commands.spawn(traversal_graph);
 
         spawn_locations_of_interest(loaded_tile_map, &mut commands);
 
-        for rendered_tile in tile_bundles {
+        let bus_layer_num = loaded_tile_map
+            .layers()
+            .position(|layer| layer.name == "Bus");
+        let (bus_tiles, other_tiles): (Vec<TileBundle>, Vec<TileBundle>) =
+            tile_bundles.into_iter().partition(|rendered_tile| {
+                bus_layer_num.is_some_and(|bus_layer_num| rendered_tile.get_z() == bus_layer_num)
+            });
+        spawn_bus(bus_tiles, &mut commands);
+        for rendered_tile in other_tiles {
             commands.spawn(rendered_tile);
         }
 
@@ -454,6 +590,49 @@ pub fn load_tiled_map(
     }
 }
 
+/// Spawns the tiles from the map's "Bus" layer as parts of a single bus,
+/// so that the whole bus moves together when it drives off.
+fn spawn_bus(bus_tiles: Vec<TileBundle>, commands: &mut Commands) {
+    let Some(first_bus_tile) = bus_tiles.first() else {
+        return;
+    };
+
+    // The bus is placed where its first tile is, and keeps that tile's
+    // logical coordinates so that it is unloaded along with the map.
+    let bus_location = first_bus_tile.logical_coordinates;
+    let bus_position = first_bus_tile.sprite_bundle.position;
+    commands
+        .spawn((Bus, bus_location, bus_position, Visibility::default()))
+        .with_children(|bus| {
+            for bus_tile in bus_tiles {
+                let mut bus_part = bus_tile.sprite_bundle;
+                bus_part.position.translation -= bus_position.translation;
+                bus.spawn(bus_part);
+            }
+        });
+}
+
+/// Moves and zooms the camera so that a newly loaded map fills the window.
+pub fn center_camera_on_map(
+    map_size: Single<&WorldTileDimensions, Added<WorldTileDimensions>>,
+    tile_size: Res<TileSize>,
+    camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    let (mut camera_position, mut camera_projection) = camera.into_inner();
+
+    // Tiles are centered on their physical position, so the map
+    // reaches half of a tile past the first and last tile.
+    let tile_size_physical = tile_size.get_size() as f32;
+    let map_physical_width = map_size.get_width() as f32 * tile_size_physical;
+    let map_physical_height = map_size.get_height() as f32 * tile_size_physical;
+    camera_position.translation.x = (map_physical_width - tile_size_physical) / 2.0;
+    camera_position.translation.y = (map_physical_height + tile_size_physical) / 2.0;
+
+    if let Projection::Orthographic(camera_projection) = camera_projection.as_mut() {
+        camera_projection.scale = 1.0 / tile_size.get_scale() as f32;
+    }
+}
+
 #[derive(Bundle, Default)]
 pub struct SpriteBundle {
     sprite: Sprite,
@@ -483,4 +662,9 @@ impl TileBundle {
             logical_coordinates,
         }
     }
+
+    /// Returns which layer of the map this tile is on.
+    pub fn get_z(&self) -> usize {
+        self.logical_coordinates.get_z()
+    }
 }
@@ -1,10 +1,48 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-
-use bevy::prelude::*;
-
-use crate::core_logic::interacting::{CamperBundle, ObjectiveAttempt};
-
-use super::setting::{LogicalCoordinates, WorldTileDimensions};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use tiled::{LayerTile, Map, Properties, PropertyValue};
+
+use crate::core_logic::{
+    MovementTime,
+    interacting::{
+        AttemptedObjective, CamperBundle, CamperInformation, ObjectiveAttempt, ScenarioAttempt,
+        ViewerIdentity,
+    },
+    joining::CamperRoster,
+    progressing::{CamperObjective, CampsiteCleared, ObjectivesErrorMessage},
+    reserving::{BusDoorQueue, QueuedCamper, TileReservations},
+};
+
+use super::setting::{
+    ChangeMap, LogicalCoordinates, TileSize, WorldTileDimensions, get_physical_from_logical,
+};
+
+/// How far in front of the map campers are drawn, so that they are
+/// never hidden behind any of the map's layers.
+const CAMPER_DEPTH: f32 = 10.0;
+
+/// The tile or tileset property deciding whether campers can walk on a tile.
+const WALKABLE_PROPERTY: &str = "walkable";
+/// The tile or tileset property deciding how much effort it takes campers
+/// to step onto a tile.
+const COST_PROPERTY: &str = "cost";
+/// The layer property marking everything drawn on a layer as something
+/// campers cannot walk through.
+const COLLISION_PROPERTY: &str = "collision";
+/// How much effort it takes to step onto a tile that does not say otherwise.
+pub const DEFAULT_MOVEMENT_COST: usize = 1;
+/// How far a step straight or diagonally goes, which is multiplied by the
+/// cost of the tile stepped onto. Diagonal steps go about 1.4 times as far.
+const STRAIGHT_STEP_DISTANCE: usize = 10;
+const DIAGONAL_STEP_DISTANCE: usize = 14;
+
+/// How many more steps than the cheapest path a camper is willing to take
+/// to get around other campers, before going the cheapest way regardless.
+const MAX_DETOUR_STEPS: usize = 32;
 
 #[derive(Clone)]
 pub struct NodeData {
@@ -24,11 +62,12 @@ impl NodeData {
 pub struct WorldNode {
     id: usize,
     data: NodeData,
+    cost: usize,
 }
 
 impl WorldNode {
-    pub fn new(id: usize, data: NodeData) -> Self {
-        Self { id, data }
+    pub fn new(id: usize, data: NodeData, cost: usize) -> Self {
+        Self { id, data, cost }
     }
 
     pub fn get_data(&self) -> &NodeData {
@@ -38,96 +77,243 @@ impl WorldNode {
     pub fn get_id(&self) -> usize {
         self.id
     }
+
+    /// Returns how much effort it takes to step onto this node from any
+    /// of its neighbors.
+    pub fn get_cost(&self) -> usize {
+        self.cost
+    }
 }
 
-pub struct AdjacencyList {
-    edges: HashMap<usize, Vec<usize>>,
+/// Represents a spot in the world that campers can walk on, along with
+/// how much effort it takes to step onto it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WalkableTile {
+    location: LogicalCoordinates,
+    cost: usize,
+}
+
+impl WalkableTile {
+    pub fn new(location: LogicalCoordinates, cost: usize) -> Self {
+        Self { location, cost }
+    }
+
+    pub fn get_location(&self) -> &LogicalCoordinates {
+        &self.location
+    }
+
+    pub fn get_cost(&self) -> usize {
+        self.cost
+    }
+}
+
+/// Returns whether some property is turned on or off, or None if it was
+/// never set.
+fn get_bool_property(properties: &Properties, property_name: &str) -> Option<bool> {
+    match properties.get(property_name) {
+        Some(PropertyValue::BoolValue(is_set)) => Some(*is_set),
+        _ => None,
+    }
 }
 
-fn insert_edge(parent_node: usize, child_node: usize, edges: &mut HashMap<usize, Vec<usize>>) {
-    edges.entry(parent_node).or_default();
+/// Returns the movement cost found in some properties, or None if it was
+/// never set. Every step costs at least something.
+fn get_cost_property(properties: &Properties) -> Option<usize> {
+    match properties.get(COST_PROPERTY) {
+        Some(PropertyValue::IntValue(cost)) => Some((*cost).max(1) as usize),
+        _ => None,
+    }
+}
+
+/// Returns a property of a tile placed on a map, falling back to the
+/// property of its whole tileset if the tile itself does not set it.
+fn get_tile_property<T>(
+    layer_tile: &LayerTile,
+    read_property: impl Fn(&Properties) -> Option<T>,
+) -> Option<T> {
+    layer_tile
+        .get_tile()
+        .and_then(|tile| read_property(&tile.properties))
+        .or_else(|| read_property(&layer_tile.get_tileset().properties))
+}
+
+/// Returns the spot at some place in a Tiled map if campers can walk on it,
+/// or None if nothing is there, or if it is blocked.
+///
+/// Tiles drawn on higher layers decide over the ones below them, so that
+/// a bridge can be walked across even with a river underneath. Anything
+/// drawn on a collision layer blocks the spot no matter what.
+fn get_walkable_tile(tiled_map: &Map, x: usize, y: usize) -> Option<WalkableTile> {
+    let mut has_tile = false;
+    let mut is_walkable = true;
+    let mut cost = DEFAULT_MOVEMENT_COST;
+    for layer in tiled_map.layers() {
+        let Some(layer_tile) = layer
+            .as_tile_layer()
+            .and_then(|tile_layer| tile_layer.get_tile(x as i32, y as i32))
+        else {
+            continue;
+        };
+
+        if get_bool_property(&layer.properties, COLLISION_PROPERTY) == Some(true) {
+            return None;
+        }
+
+        has_tile = true;
+        is_walkable = get_tile_property(&layer_tile, |properties| {
+            get_bool_property(properties, WALKABLE_PROPERTY)
+        })
+        .unwrap_or(is_walkable);
+        cost = get_tile_property(&layer_tile, get_cost_property).unwrap_or(cost);
+    }
+
+    let location = LogicalCoordinates::new(x, y, 0);
+    (has_tile && is_walkable).then(|| WalkableTile::new(location, cost))
+}
+
+/// The directions a node can be connected to its neighbors in, in the
+/// order that its neighbors are explored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridDirection {
+    Left,
+    Up,
+    Right,
+    Down,
+}
+
+impl GridDirection {
+    const ALL: [GridDirection; 4] = [
+        GridDirection::Left,
+        GridDirection::Up,
+        GridDirection::Right,
+        GridDirection::Down,
+    ];
+
+    /// Returns the bit marking an edge in this direction.
+    fn get_flag(&self) -> u8 {
+        1 << (*self as u8)
+    }
+
+    /// Returns the direction leading back to where this one came from.
+    fn get_opposite(&self) -> Self {
+        match self {
+            GridDirection::Left => GridDirection::Right,
+            GridDirection::Up => GridDirection::Down,
+            GridDirection::Right => GridDirection::Left,
+            GridDirection::Down => GridDirection::Up,
+        }
+    }
+
+    /// Returns the ID of the node next to some node in this direction,
+    /// assuming that it is within the world.
+    fn get_neighbor_id(&self, node_id: usize, world_size: &WorldTileDimensions) -> usize {
+        match self {
+            GridDirection::Left => node_id - 1,
+            GridDirection::Up => node_id - world_size.get_width(),
+            GridDirection::Right => node_id + 1,
+            GridDirection::Down => node_id + world_size.get_width(),
+        }
+    }
+}
 
-    edges
-        .get_mut(&parent_node)
-        .expect("insert_edge: Could not find the edge for the given parent node")
-        .push(child_node);
+/// Represents which neighbors every spot in the world is connected to,
+/// where each spot keeps one bit per direction.
+pub struct AdjacencyList {
+    world_size: WorldTileDimensions,
+    edges: Vec<u8>,
 }
 
 impl AdjacencyList {
-    pub fn from_tile_nodes(nodes: &Vec<WorldNode>, world_size: &WorldTileDimensions) -> Self {
-        let mut edges = HashMap::new();
+    /// Connects every node to the nodes directly left, right, above and
+    /// below it, where `nodes` is laid out by node ID.
+    pub fn from_tile_nodes(nodes: &[Option<WorldNode>], world_size: &WorldTileDimensions) -> Self {
+        let mut edges = vec![0; nodes.len()];
 
-        for node in nodes {
+        for node in nodes.iter().flatten() {
             let current_node_location = node.get_data().get_location();
             let current_node_id = node.get_id();
+
+            let mut earlier_neighbors = Vec::with_capacity(2);
             if current_node_location.get_x() > 0 {
-                let left_node_position = LogicalCoordinates::new(
-                    current_node_location.get_x() - 1,
-                    current_node_location.get_y(),
-                    0,
-                );
-                let left_node_id = left_node_position.to_1d(world_size);
-
-                let left_node_found_in_nodes = nodes
-                    .binary_search_by(|node| node.get_id().cmp(&left_node_id))
-                    .is_ok();
-                if left_node_found_in_nodes {
-                    insert_edge(current_node_id, left_node_id, &mut edges);
-                    insert_edge(left_node_id, current_node_id, &mut edges);
-                }
+                earlier_neighbors.push(GridDirection::Left);
             }
-
             if current_node_location.get_y() > 0 {
-                let top_node_position = LogicalCoordinates::new(
-                    current_node_location.get_x(),
-                    current_node_location.get_y() - 1,
-                    0,
-                );
-                let top_node_id = top_node_position.to_1d(world_size);
-
-                let top_node_found_in_nodes = nodes
-                    .binary_search_by(|node| node.get_id().cmp(&top_node_id))
-                    .is_ok();
-                if top_node_found_in_nodes {
-                    insert_edge(current_node_id, top_node_id, &mut edges);
-                    insert_edge(top_node_id, current_node_id, &mut edges);
+                earlier_neighbors.push(GridDirection::Up);
+            }
+
+            for direction in earlier_neighbors {
+                let neighbor_node_id = direction.get_neighbor_id(current_node_id, world_size);
+                if nodes[neighbor_node_id].is_none() {
+                    continue;
                 }
+
+                edges[current_node_id] |= direction.get_flag();
+                edges[neighbor_node_id] |= direction.get_opposite().get_flag();
             }
         }
 
-        Self { edges }
+        Self {
+            world_size: *world_size,
+            edges,
+        }
     }
 
-    pub fn get_edges(&self, node_id: usize) -> &Vec<usize> {
-        &self.edges[&node_id]
+    /// Returns the IDs of every node connected to some node, going left,
+    /// up, right, then down.
+    pub fn get_edges(&self, node_id: usize) -> impl Iterator<Item = usize> + '_ {
+        let node_edges = self.edges.get(node_id).copied().unwrap_or_default();
+
+        GridDirection::ALL
+            .iter()
+            .filter(move |direction| node_edges & direction.get_flag() != 0)
+            .map(move |direction| direction.get_neighbor_id(node_id, &self.world_size))
     }
 }
 
+/// Represents every spot in the world that campers can walk on, laid out
+/// as a grid so that any spot can be looked up by its node ID.
 #[derive(Component)]
 pub struct Graph {
     world_size: WorldTileDimensions,
-    nodes: Vec<WorldNode>,
+    nodes: Vec<Option<WorldNode>>,
     edges: AdjacencyList,
 }
 
 impl Graph {
-    pub fn from_tiles(tiles: &Vec<LogicalCoordinates>, world_size: &WorldTileDimensions) -> Self {
-        let mut nodes = Vec::new();
+    /// Returns a Graph of every spot in a Tiled map that campers can walk
+    /// on, going by the `walkable` and `cost` properties of its tiles and
+    /// tilesets, and the `collision` property of its layers.
+    ///
+    /// Any spot with a tile on it can be walked on for the default cost
+    /// unless the map says otherwise.
+    pub fn from_tiles(tiled_map: &Map, world_size: &WorldTileDimensions) -> Self {
+        let walkable_tiles: Vec<WalkableTile> = (0..world_size.get_height())
+            .flat_map(|y| (0..world_size.get_width()).map(move |x| (x, y)))
+            .filter_map(|(x, y)| get_walkable_tile(tiled_map, x, y))
+            .collect();
+
+        Self::from_walkable_tiles(&walkable_tiles, world_size)
+    }
 
-        let mut seen_node_ids = HashSet::new();
-        for tile_location in tiles {
-            let node_data = NodeData::new(*tile_location);
+    /// Returns a Graph connecting every walkable tile to the walkable
+    /// tiles next to it.
+    pub fn from_walkable_tiles(tiles: &[WalkableTile], world_size: &WorldTileDimensions) -> Self {
+        let mut nodes: Vec<Option<WorldNode>> = Vec::new();
+        nodes.resize_with(world_size.get_width() * world_size.get_height(), || None);
+
+        for walkable_tile in tiles {
+            let tile_location = walkable_tile.get_location();
             let node_id = tile_location.to_1d(world_size);
-            if seen_node_ids.contains(&node_id) {
+            let node_slot = nodes
+                .get_mut(node_id)
+                .expect("from_walkable_tiles: Tile is outside of the world.");
+            if node_slot.is_some() {
                 continue;
             }
 
-            let tile_node = WorldNode::new(node_id, node_data);
-            nodes.push(tile_node);
-
-            seen_node_ids.insert(node_id);
+            let node_data = NodeData::new(*tile_location);
+            *node_slot = Some(WorldNode::new(node_id, node_data, walkable_tile.get_cost()));
         }
-        nodes.sort_by_key(|node1| node1.get_id());
 
         let edges = AdjacencyList::from_tile_nodes(&nodes, world_size);
 
@@ -139,41 +325,124 @@ impl Graph {
     }
 
     pub fn get_node_at(&self, position: &LogicalCoordinates) -> &WorldNode {
-        let position_1d = position.to_1d(&self.world_size);
-
-        self.nodes
-            .iter()
-            .find(|node| node.get_id() == position_1d)
+        self.find_node_at(position)
             .expect("get_node_at: Could not find node")
     }
 
+    /// Returns the node at some spot in the world, or None if campers
+    /// cannot walk there.
+    pub fn find_node_at(&self, position: &LogicalCoordinates) -> Option<&WorldNode> {
+        let is_within_world = position.get_x() < self.world_size.get_width()
+            && position.get_y() < self.world_size.get_height();
+        if !is_within_world {
+            return None;
+        }
+
+        self.nodes[position.to_1d(&self.world_size)].as_ref()
+    }
+
+    /// Returns whether campers can walk on some spot in the world.
+    pub fn has_node_at(&self, position: &LogicalCoordinates) -> bool {
+        self.find_node_at(position).is_some()
+    }
+
     pub fn get_node_by_id(&self, desired_node_id: usize) -> &WorldNode {
         self.nodes
-            .iter()
-            .find(|node| node.get_id() == desired_node_id)
+            .get(desired_node_id)
+            .and_then(Option::as_ref)
             .expect("get_node_by_id: Could not find node.")
     }
 
-    pub fn get_edges(&self, node: &WorldNode) -> Vec<&WorldNode> {
-        let mut node_edges = Vec::new();
+    /// Returns every node connected to some node, going left, up, right,
+    /// then down.
+    pub fn get_edges(&self, node: &WorldNode) -> impl Iterator<Item = &WorldNode> {
+        self.edges
+            .get_edges(node.get_id())
+            .map(|node_id| self.get_node_by_id(node_id))
+    }
 
-        let found_node_edges = self.edges.get_edges(node.get_id());
+    /// Returns every node connected to some node, along with how much
+    /// effort it takes to step onto each of them.
+    pub fn get_weighted_edges(
+        &self,
+        node: &WorldNode,
+    ) -> impl Iterator<Item = (&WorldNode, usize)> {
+        self.get_edges(node)
+            .map(|next_node| (next_node, next_node.get_cost()))
+    }
 
-        for node_id in found_node_edges {
-            let found_node = self.get_node_by_id(*node_id);
-            node_edges.push(found_node);
-        }
+    /// Returns every node diagonally across from some node, going up-left,
+    /// up-right, down-left, then down-right. A diagonal step is only made
+    /// when both spots beside it can be walked on, so that campers never
+    /// cut across the corner of a tree or a river.
+    pub fn get_diagonal_edges(&self, node: &WorldNode) -> impl Iterator<Item = &WorldNode> {
+        let location = *node.get_data().get_location();
+
+        [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .into_iter()
+            .filter_map(move |(x_step, y_step)| {
+                let next_x = location.get_x().checked_add_signed(x_step)?;
+                let next_y = location.get_y().checked_add_signed(y_step)?;
+
+                let beside_location = LogicalCoordinates::new(next_x, location.get_y(), 0);
+                let above_or_below_location = LogicalCoordinates::new(location.get_x(), next_y, 0);
+                if !self.has_node_at(&beside_location)
+                    || !self.has_node_at(&above_or_below_location)
+                {
+                    return None;
+                }
 
-        node_edges
+                self.find_node_at(&LogicalCoordinates::new(next_x, next_y, 0))
+            })
+    }
+}
+
+/// How campers are allowed to step from one tile onto the next.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Movement {
+    /// Only ever left, up, right or down.
+    #[default]
+    Straight,
+    /// Straight, as well as diagonally between tiles.
+    Diagonal,
+}
+
+impl Movement {
+    /// Returns a guess of how far apart two spots are, which is never
+    /// more than the cheapest path between them could cost.
+    ///
+    /// This is the Manhattan distance when only moving straight, since
+    /// every step costs at least as much as its distance.
+    fn estimate_distance(
+        &self,
+        source: &LogicalCoordinates,
+        destination: &LogicalCoordinates,
+    ) -> usize {
+        let x_distance = source.get_x().abs_diff(destination.get_x());
+        let y_distance = source.get_y().abs_diff(destination.get_y());
+
+        match self {
+            Movement::Straight => (x_distance + y_distance) * STRAIGHT_STEP_DISTANCE,
+            Movement::Diagonal => {
+                let num_diagonal_steps = x_distance.min(y_distance);
+                let num_straight_steps = x_distance.max(y_distance) - num_diagonal_steps;
+
+                (num_diagonal_steps * DIAGONAL_STEP_DISTANCE)
+                    + (num_straight_steps * STRAIGHT_STEP_DISTANCE)
+            }
+        }
     }
 }
 
 #[derive(Component)]
 pub struct Pathfinding {
     path: VecDeque<NodeData>,
+    has_left_start: bool,
 }
 
-fn get_bfs_path(
+/// Returns the path to a target node, following back which node each
+/// node was discovered by until reaching the source node.
+fn get_discovered_path(
     source_node: &WorldNode,
     target_node: &WorldNode,
     discovered_by: &HashMap<usize, usize>,
@@ -198,47 +467,118 @@ fn get_bfs_path(
     path
 }
 
-fn visit_dfs_recursive(
-    current_node: &WorldNode,
-    path: &mut VecDeque<NodeData>,
-    visited_nodes: &mut HashSet<usize>,
+/// Where a camper is while planning around reserved spots: which node they
+/// stand on, how many steps from now, and whether they have left the spot
+/// they started from yet.
+type ReservedState = (usize, usize, bool);
+
+/// Returns the path to a state reached while planning around reserved
+/// spots, following back which state each state was reached from.
+fn get_reserved_path(
+    target_state: ReservedState,
+    reached_from: &HashMap<ReservedState, ReservedState>,
     world_graph: &Graph,
-) {
-    let current_node_id = current_node.get_id();
-    if visited_nodes.contains(&current_node_id) {
-        return;
+) -> VecDeque<NodeData> {
+    let mut path = VecDeque::new();
+    let mut current_state = Some(target_state);
+    while let Some(state) = current_state {
+        let (node_id, _step_offset, _has_left_start) = state;
+        path.push_front(world_graph.get_node_by_id(node_id).get_data().clone());
+
+        current_state = reached_from.get(&state).copied();
     }
 
-    let current_node_data = current_node.get_data();
-    path.push_back(current_node_data.clone());
-    visited_nodes.insert(current_node_id);
+    path
+}
+
+/// Returns the path from some node to the closest node that has not been
+/// visited yet, or None once every node it can reach has been visited.
+fn find_nearest_unvisited(
+    source_node: &WorldNode,
+    visited_nodes: &HashSet<usize>,
+    world_graph: &Graph,
+) -> Option<VecDeque<NodeData>> {
+    let mut nodes_to_visit = VecDeque::from([source_node]);
+    let mut seen_nodes = HashSet::from([source_node.get_id()]);
+    let mut discovered_by = HashMap::new();
+    while let Some(node_to_visit) = nodes_to_visit.pop_front() {
+        if !visited_nodes.contains(&node_to_visit.get_id()) {
+            return Some(get_discovered_path(
+                source_node,
+                node_to_visit,
+                &discovered_by,
+                world_graph,
+            ));
+        }
 
-    let current_node_edges = world_graph.get_edges(current_node);
-    for next_node in current_node_edges {
-        visit_dfs_recursive(next_node, path, visited_nodes, world_graph);
-        path.push_back(current_node_data.clone());
+        for next_node in world_graph.get_edges(node_to_visit) {
+            if seen_nodes.insert(next_node.get_id()) {
+                discovered_by.insert(next_node.get_id(), node_to_visit.get_id());
+                nodes_to_visit.push_back(next_node);
+            }
+        }
     }
+
+    None
 }
 
 impl Pathfinding {
-    pub fn explore_all(source: &LogicalCoordinates, world_graph: &Graph) -> Self {
-        let source_node = world_graph.get_node_at(source);
+    fn new(path: VecDeque<NodeData>) -> Self {
+        Self {
+            path,
+            has_left_start: false,
+        }
+    }
 
-        let mut path = VecDeque::new();
-        let mut visited_nodes = HashSet::new();
+    /// Returns a route from some spot that visits every spot it can reach,
+    /// or None if the spot is not on the map.
+    pub fn explore_all(source: &LogicalCoordinates, world_graph: &Graph) -> Option<Self> {
+        Self::patrol(source, world_graph, usize::MAX)
+    }
 
-        visit_dfs_recursive(source_node, &mut path, &mut visited_nodes, world_graph);
+    /// Returns a patrol route from some spot that keeps heading to the
+    /// closest spot it has not been to yet. The patrol ends once every spot
+    /// it can reach has been visited, or once it has taken as many steps as
+    /// it is allowed to. Returns None if the spot is not on the map.
+    pub fn patrol(
+        source: &LogicalCoordinates,
+        world_graph: &Graph,
+        max_steps: usize,
+    ) -> Option<Self> {
+        let source_node = world_graph.find_node_at(source)?;
+
+        let mut path = VecDeque::from([source_node.get_data().clone()]);
+        let mut visited_nodes = HashSet::from([source_node.get_id()]);
+        let mut current_node = source_node;
+        while path.len() <= max_steps {
+            let Some(path_to_unvisited) =
+                find_nearest_unvisited(current_node, &visited_nodes, world_graph)
+            else {
+                break;
+            };
 
-        Self { path }
+            let num_steps_left = max_steps - (path.len() - 1);
+            for next_node_data in path_to_unvisited.into_iter().skip(1).take(num_steps_left) {
+                let next_node = world_graph.find_node_at(next_node_data.get_location())?;
+                visited_nodes.insert(next_node.get_id());
+                current_node = next_node;
+
+                path.push_back(next_node_data);
+            }
+        }
+
+        Some(Self::new(path))
     }
 
+    /// Returns a path taking the fewest steps between two spots, no matter
+    /// how costly those steps are, or None if there is no way to get there.
     pub fn shortest_path(
         source: &LogicalCoordinates,
         destination: &LogicalCoordinates,
         world_graph: &Graph,
-    ) -> Self {
+    ) -> Option<Self> {
         let mut nodes_to_visit: VecDeque<&WorldNode> = VecDeque::new();
-        let source_node = world_graph.get_node_at(source);
+        let source_node = world_graph.find_node_at(source)?;
         nodes_to_visit.push_back(source_node);
 
         let mut visited_nodes = HashSet::new();
@@ -267,17 +607,183 @@ impl Pathfinding {
             }
         }
 
-        let target_node = found_target_node.unwrap_or_else(|| {
-            panic!(
-                r#"shortest_path: Could not find destination for node {}, {}"#,
-                destination.get_x(),
-                destination.get_y()
-            )
-        });
+        let target_node = found_target_node?;
+        let path = get_discovered_path(source_node, target_node, &discovered_by, world_graph);
+
+        Some(Self::new(path))
+    }
+
+    /// Returns a path costing the least effort between two spots, going by
+    /// how costly each tile is to step onto, or None if there is no way to
+    /// get there.
+    pub fn cheapest_path(
+        source: &LogicalCoordinates,
+        destination: &LogicalCoordinates,
+        world_graph: &Graph,
+        movement: Movement,
+    ) -> Option<Self> {
+        let source_node = world_graph.find_node_at(source)?;
+        let target_node = world_graph.find_node_at(destination)?;
 
-        let path = get_bfs_path(source_node, target_node, &discovered_by, world_graph);
+        let mut nodes_to_visit = BinaryHeap::new();
+        nodes_to_visit.push(Reverse((
+            movement.estimate_distance(source, destination),
+            source_node.get_id(),
+        )));
 
-        Self { path }
+        let mut visited_nodes = HashSet::new();
+        let mut discovered_by = HashMap::new();
+        let mut effort_so_far = HashMap::from([(source_node.get_id(), 0)]);
+        while let Some(Reverse((_estimated_effort, node_id))) = nodes_to_visit.pop() {
+            if node_id == target_node.get_id() {
+                let path =
+                    get_discovered_path(source_node, target_node, &discovered_by, world_graph);
+                return Some(Self::new(path));
+            }
+
+            if !visited_nodes.insert(node_id) {
+                continue;
+            }
+
+            let node_to_visit = world_graph.get_node_by_id(node_id);
+            let straight_steps = world_graph
+                .get_weighted_edges(node_to_visit)
+                .map(|(next_node, cost)| (next_node, cost * STRAIGHT_STEP_DISTANCE));
+            let diagonal_steps = (movement == Movement::Diagonal)
+                .then(|| world_graph.get_diagonal_edges(node_to_visit))
+                .into_iter()
+                .flatten()
+                .map(|next_node| (next_node, next_node.get_cost() * DIAGONAL_STEP_DISTANCE));
+
+            let node_effort = effort_so_far[&node_id];
+            for (next_node, step_effort) in straight_steps.chain(diagonal_steps) {
+                let next_node_id = next_node.get_id();
+                let next_node_effort = node_effort + step_effort;
+                let is_cheaper = effort_so_far
+                    .get(&next_node_id)
+                    .is_none_or(|known_effort| next_node_effort < *known_effort);
+                if !is_cheaper {
+                    continue;
+                }
+
+                effort_so_far.insert(next_node_id, next_node_effort);
+                discovered_by.insert(next_node_id, node_id);
+
+                let next_node_location = next_node.get_data().get_location();
+                let estimated_effort =
+                    next_node_effort + movement.estimate_distance(next_node_location, destination);
+                nodes_to_visit.push(Reverse((estimated_effort, next_node_id)));
+            }
+        }
+
+        None
+    }
+
+    /// Returns a path costing the least effort between two spots that
+    /// stays clear of every spot other campers have reserved, by waiting
+    /// for them or walking around them, or None if there is no way to get
+    /// there without running into someone.
+    ///
+    /// Each spot on the path is stood on for one step, starting from the
+    /// current step. Campers may wait where they start for as long as they
+    /// need to, since they are still coming out of somewhere.
+    pub fn reserved_path(
+        source: &LogicalCoordinates,
+        destination: &LogicalCoordinates,
+        world_graph: &Graph,
+        tile_reservations: &TileReservations,
+        viewer: &ViewerIdentity,
+    ) -> Option<Self> {
+        let source_node = world_graph.find_node_at(source)?;
+        let target_node = world_graph.find_node_at(destination)?;
+        let cheapest_path =
+            Self::cheapest_path(source, destination, world_graph, Movement::Straight)?;
+        let max_step_offset = cheapest_path.path.len() + MAX_DETOUR_STEPS;
+
+        let current_step = tile_reservations.get_current_step();
+        let is_taken = |location: &LogicalCoordinates, step_offset: usize| {
+            tile_reservations.is_reserved_by_other(location, current_step + step_offset, viewer)
+        };
+
+        let start_state = (source_node.get_id(), 0, false);
+        let mut states_to_visit = BinaryHeap::from([Reverse((
+            Movement::Straight.estimate_distance(source, destination),
+            start_state,
+        ))]);
+
+        let mut visited_states = HashSet::new();
+        let mut reached_from = HashMap::new();
+        let mut effort_so_far = HashMap::from([(start_state, 0)]);
+        while let Some(Reverse((_estimated_effort, state))) = states_to_visit.pop() {
+            let (node_id, step_offset, has_left_start) = state;
+            if node_id == target_node.get_id() {
+                let path = get_reserved_path(state, &reached_from, world_graph);
+                return Some(Self::new(path));
+            }
+
+            if step_offset == max_step_offset || !visited_states.insert(state) {
+                continue;
+            }
+
+            let node_to_visit = world_graph.get_node_by_id(node_id);
+            let location = node_to_visit.get_data().get_location();
+            let waiting = (node_to_visit, STRAIGHT_STEP_DISTANCE);
+            let walking = world_graph
+                .get_weighted_edges(node_to_visit)
+                .map(|(next_node, cost)| (next_node, cost * STRAIGHT_STEP_DISTANCE));
+
+            let next_step_offset = step_offset + 1;
+            let state_effort = effort_so_far[&state];
+            for (next_node, step_effort) in std::iter::once(waiting).chain(walking) {
+                let next_location = next_node.get_data().get_location();
+                let is_waiting = next_node.get_id() == node_id;
+                let is_waiting_at_start = is_waiting && !has_left_start;
+                if !is_waiting_at_start && is_taken(next_location, next_step_offset) {
+                    continue;
+                }
+
+                // Campers cannot walk through each other by swapping spots.
+                let is_swapping = !is_waiting
+                    && tile_reservations
+                        .get_reserver(next_location, current_step + step_offset)
+                        .is_some_and(|reserver| {
+                            reserver != viewer
+                                && tile_reservations
+                                    .get_reserver(location, current_step + next_step_offset)
+                                    == Some(reserver)
+                        });
+                if is_swapping {
+                    continue;
+                }
+
+                let next_state = (
+                    next_node.get_id(),
+                    next_step_offset,
+                    has_left_start || !is_waiting,
+                );
+                let next_state_effort = state_effort + step_effort;
+                let is_cheaper = effort_so_far
+                    .get(&next_state)
+                    .is_none_or(|known_effort| next_state_effort < *known_effort);
+                if !is_cheaper {
+                    continue;
+                }
+
+                effort_so_far.insert(next_state, next_state_effort);
+                reached_from.insert(next_state, state);
+
+                let estimated_effort = next_state_effort
+                    + Movement::Straight.estimate_distance(next_location, destination);
+                states_to_visit.push(Reverse((estimated_effort, next_state)));
+            }
+        }
+
+        None
+    }
+
+    /// Returns a path that stays put on some spot.
+    pub fn standing_still(location: &LogicalCoordinates) -> Self {
+        Self::new(VecDeque::from([NodeData::new(*location)]))
     }
 
     pub fn get_destination(&self) -> &LogicalCoordinates {
@@ -292,8 +798,18 @@ impl Pathfinding {
         !self.path.is_empty()
     }
 
-    pub fn pop_front(&mut self) -> NodeData {
-        self.path.pop_front().expect("pop_front: No nodes found.")
+    /// Returns the next spot to head to along the path, or None once the
+    /// path has been walked. The spot the path starts from is skipped if
+    /// already standing on it, while any other spot that repeats the one
+    /// before it means waiting there for a step.
+    pub fn next_step(&mut self, current_location: &LogicalCoordinates) -> Option<NodeData> {
+        let mut next_node = self.path.pop_front()?;
+        if !self.has_left_start && next_node.get_location() == current_location {
+            next_node = self.path.pop_front()?;
+        }
+
+        self.has_left_start = true;
+        Some(next_node)
     }
 
     pub fn get_locations(&self) -> Vec<LogicalCoordinates> {
@@ -364,11 +880,10 @@ impl PathTarget {
         self.current_position
     }
 
+    /// Returns whether the target has been reached, which takes the full
+    /// movement time even when waiting on the same spot.
     pub fn has_been_reached(&self) -> bool {
-        let at_x = self.current_position.translation.x == self.physical_target.translation.x;
-        let at_y = self.current_position.translation.y == self.physical_target.translation.y;
-
-        at_x && at_y
+        self.movement_timer.is_finished()
     }
 
     pub fn get_logical_target(&self) -> LogicalCoordinates {
@@ -376,34 +891,565 @@ impl PathTarget {
     }
 }
 
+/// Represents a camper reaching the end of the path they were traveling.
+#[derive(Message, Clone, Debug)]
+pub struct CamperArrived {
+    viewer: ViewerIdentity,
+    destination: LogicalCoordinates,
+}
+
+impl CamperArrived {
+    pub fn new(viewer: ViewerIdentity, destination: LogicalCoordinates) -> Self {
+        Self {
+            viewer,
+            destination,
+        }
+    }
+
+    pub fn get_viewer(&self) -> &ViewerIdentity {
+        &self.viewer
+    }
+
+    pub fn get_destination(&self) -> &LogicalCoordinates {
+        &self.destination
+    }
+}
+
+/// Where a camper is in their trip from the bus, into the meadows, and back.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum CamperJourney {
+    /// Walking from outside of the bus to the meadows.
+    HeadingToMeadows,
+    /// Out of sight in the meadows, waiting for their scenario to be resolved.
+    InMeadows,
+    /// Walking from the meadows back to the bus after their scenario.
+    HeadingToBus,
+}
+
 #[derive(Component, PartialEq)]
 pub struct OutsideOfBus;
 
 #[derive(Component, PartialEq)]
 pub struct MeadowEntrance;
 
-/// Spawns and moves a camper to the meadows when they attempt an objective.
-pub fn move_camper_to_meadows(
+/// Where the bus drives off to once a campsite is cleared.
+#[derive(Component, PartialEq)]
+pub struct BusExit;
+
+/// The bus campers ride between campsites, drawn from the map's "Bus" layer.
+///
+/// Each tile of the bus is a child of it, so moving the bus moves them all.
+#[derive(Component, PartialEq)]
+pub struct Bus;
+
+/// Represents the bus driving away from the campsite.
+///
+/// The bus follows the path its door takes from outside of the bus to the
+/// exit, staying the same distance away from its door the whole way.
+#[derive(Component)]
+pub struct DepartingBus {
+    door_location: LogicalCoordinates,
+    door_offset: Vec2,
+}
+
+impl DepartingBus {
+    pub fn new(door_location: LogicalCoordinates, door_offset: Vec2) -> Self {
+        Self {
+            door_location,
+            door_offset,
+        }
+    }
+
+    pub fn get_door_location(&self) -> &LogicalCoordinates {
+        &self.door_location
+    }
+}
+
+/// The assets needed to show campers on the map.
+#[derive(SystemParam)]
+pub struct CamperSprites<'w> {
+    asset_server: Res<'w, AssetServer>,
+    texture_atlas_layouts: ResMut<'w, Assets<TextureAtlasLayout>>,
+}
+
+impl CamperSprites<'_> {
+    /// Returns the sprite used to show a camper on the map.
+    fn get_camper_sprite(&mut self) -> Sprite {
+        let camper_image = self.asset_server.load("environment_pack/NPC_test.png");
+        let camper_atlas_layout =
+            TextureAtlasLayout::from_grid(UVec2::new(16, 32), 4, 4, None, None);
+        let camper_atlas = TextureAtlas {
+            layout: self.texture_atlas_layouts.add(camper_atlas_layout),
+            index: 0,
+        };
+
+        Sprite::from_atlas_image(camper_image, camper_atlas)
+    }
+}
+
+/// Returns the cheapest path between two spots for anything moving around
+/// the campsite, or a path staying put if there is no way to get there.
+fn get_travel_path(
+    source: &LogicalCoordinates,
+    destination: &LogicalCoordinates,
+    world_graph: &Graph,
+) -> Pathfinding {
+    Pathfinding::cheapest_path(source, destination, world_graph, Movement::Straight).unwrap_or_else(
+        || {
+            warn!(
+                "get_travel_path: Could not find a way from {}, {} to {}, {}.",
+                source.get_x(),
+                source.get_y(),
+                destination.get_x(),
+                destination.get_y()
+            );
+            Pathfinding::standing_still(source)
+        },
+    )
+}
+
+/// What campers need to plan paths around each other.
+#[derive(SystemParam)]
+pub struct CamperRoutes<'w, 's> {
+    traversal_graph: Single<'w, 's, &'static Graph>,
+    tile_reservations: ResMut<'w, TileReservations>,
+}
+
+impl CamperRoutes<'_, '_> {
+    /// Returns the path a viewer's camper takes between two spots around
+    /// every other camper, reserving each spot along the way. Campers with
+    /// no way around everyone else go the cheapest way regardless.
+    fn reserve_travel_path(
+        &mut self,
+        source: &LogicalCoordinates,
+        destination: &LogicalCoordinates,
+        viewer: &ViewerIdentity,
+    ) -> Pathfinding {
+        let travel_path = Pathfinding::reserved_path(
+            source,
+            destination,
+            *self.traversal_graph,
+            &self.tile_reservations,
+            viewer,
+        )
+        .unwrap_or_else(|| get_travel_path(source, destination, *self.traversal_graph));
+        self.tile_reservations
+            .reserve_path(viewer, &travel_path.get_locations());
+
+        travel_path
+    }
+
+    /// Returns whether some camper is standing on a spot right now.
+    fn is_spot_taken(&self, location: &LogicalCoordinates) -> bool {
+        let current_step = self.tile_reservations.get_current_step();
+        self.tile_reservations.is_reserved(location, current_step)
+    }
+}
+
+/// Lines a camper up inside of the bus when they attempt an objective,
+/// to step out once it is their turn.
+///
+/// Viewers who are not on the Camper Roster have no camper to send out.
+pub fn queue_campers_at_bus_door(
     mut objective_attempts: MessageReader<ObjectiveAttempt>,
+    campers: Query<&CamperInformation>,
+    camper_roster: Res<CamperRoster>,
+    mut bus_door_queue: ResMut<BusDoorQueue>,
+) {
+    for objective_attempt in objective_attempts.read() {
+        if !camper_roster.has_camper(objective_attempt.get_viewer()) {
+            continue;
+        }
+
+        let camper_already_out = campers
+            .iter()
+            .any(|camper_info| camper_info.get_viewer() == objective_attempt.get_viewer());
+        if camper_already_out {
+            continue;
+        }
+
+        bus_door_queue.join(QueuedCamper::new(
+            objective_attempt.get_viewer().clone(),
+            objective_attempt.get_objective().clone(),
+        ));
+    }
+}
+
+/// Spawns the next camper waiting in the bus outside of its door and moves
+/// them to the meadows, once nobody else is standing in the doorway.
+pub fn move_camper_to_meadows(
+    mut bus_door_queue: ResMut<BusDoorQueue>,
     outside_of_bus_location: Single<(&LogicalCoordinates, &Transform), With<OutsideOfBus>>,
     meadow_location: Single<&LogicalCoordinates, With<MeadowEntrance>>,
-    traversal_graph: Single<&Graph>,
+    mut camper_routes: CamperRoutes,
+    mut camper_sprites: CamperSprites,
     mut commands: Commands,
 ) {
     let (outside_of_bus_logical_location, outside_of_bus_physical_location) =
         *outside_of_bus_location;
-    for objective_attempt in objective_attempts.read() {
-        let path_to_meadows = Pathfinding::shortest_path(
-            outside_of_bus_logical_location,
-            *meadow_location,
-            *traversal_graph,
-        );
+    if camper_routes.is_spot_taken(outside_of_bus_logical_location) {
+        return;
+    }
 
-        commands.spawn(CamperBundle::new(
-            objective_attempt.get_camper_name(),
-            *outside_of_bus_physical_location,
+    let Some(queued_camper) = bus_door_queue.pop_next() else {
+        return;
+    };
+
+    let path_to_meadows = camper_routes.reserve_travel_path(
+        outside_of_bus_logical_location,
+        *meadow_location,
+        queued_camper.get_viewer(),
+    );
+
+    let mut camper_physical_location = *outside_of_bus_physical_location;
+    camper_physical_location.translation.z = CAMPER_DEPTH;
+
+    commands.spawn((
+        CamperBundle::new(
+            queued_camper.get_viewer(),
+            camper_physical_location,
             *outside_of_bus_logical_location,
             path_to_meadows,
+            CamperJourney::HeadingToMeadows,
+        ),
+        AttemptedObjective::new(queued_camper.get_objective().clone()),
+        camper_sprites.get_camper_sprite(),
+    ));
+}
+
+/// Forgets every reserved spot and camper waiting in the bus whenever the
+/// map changes, since the campers go with it.
+pub fn forget_camper_reservations(
+    mut change_map_requests: MessageReader<ChangeMap>,
+    mut tile_reservations: ResMut<TileReservations>,
+    mut bus_door_queue: ResMut<BusDoorQueue>,
+) {
+    if change_map_requests.read().last().is_none() {
+        return;
+    }
+
+    tile_reservations.clear();
+    bus_door_queue.clear();
+}
+
+/// What is needed to turn the next spot on a path into somewhere to move
+/// to on the map.
+#[derive(SystemParam)]
+pub struct PathTargeting<'w, 's> {
+    movement_time: Res<'w, MovementTime>,
+    tile_size: Res<'w, TileSize>,
+    map_size: Single<'w, 's, &'static WorldTileDimensions>,
+}
+
+impl PathTargeting<'_, '_> {
+    /// Returns where something should head to next on their path, skipping
+    /// over the spot they start from, or None if they have arrived.
+    ///
+    /// The physical offset is added to where each spot on the path is
+    /// drawn, for anything that does not stand right on the path it follows.
+    fn get_next_path_target(
+        &self,
+        travel_path: &mut Pathfinding,
+        logical_location: &LogicalCoordinates,
+        physical_location: &Transform,
+        physical_offset: Vec2,
+    ) -> Option<PathTarget> {
+        let next_location = *travel_path.next_step(logical_location)?.get_location();
+        let next_physical_location =
+            get_physical_from_logical(&next_location, self.tile_size.get_size(), *self.map_size)
+                + physical_offset;
+        let next_physical_location = Transform::from_xyz(
+            next_physical_location.x,
+            next_physical_location.y,
+            physical_location.translation.z,
+        );
+
+        Some(PathTarget::new(
+            next_location,
+            next_physical_location,
+            *physical_location,
+            self.movement_time.get_timer(),
+        ))
+    }
+}
+
+/// The parts of a camper needed to walk them along their path.
+type TravelingCamper<'a> = (
+    Entity,
+    &'a CamperInformation,
+    &'a mut Pathfinding,
+    &'a mut Transform,
+    &'a mut LogicalCoordinates,
+    Option<&'a mut PathTarget>,
+);
+
+/// Walks campers along their paths one tile at a time, letting everyone know
+/// once a camper arrives at their destination.
+///
+/// Campers step in lockstep, where nobody heads to their next spot until
+/// every camper has finished moving to their last one, so that everyone
+/// stands where their reserved spots say they do.
+pub fn move_campers_along_path(
+    mut campers: Query<TravelingCamper>,
+    path_targeting: PathTargeting,
+    mut tile_reservations: ResMut<TileReservations>,
+    time: Res<Time>,
+    mut camper_arrival_broadcaster: MessageWriter<CamperArrived>,
+    mut commands: Commands,
+) {
+    let is_next_step = campers
+        .iter()
+        .all(|(_, _, _, _, _, path_target)| path_target.is_none());
+    if is_next_step {
+        tile_reservations.start_next_step();
+    }
+
+    for (
+        camper_entity,
+        camper_info,
+        mut travel_path,
+        mut camper_physical_location,
+        mut camper_logical_location,
+        path_target,
+    ) in &mut campers
+    {
+        let mut new_path_target = None;
+        let path_target = match path_target {
+            Some(path_target) => path_target.into_inner(),
+            None if !is_next_step => continue,
+            None => {
+                let Some(next_path_target) = path_targeting.get_next_path_target(
+                    &mut travel_path,
+                    &camper_logical_location,
+                    &camper_physical_location,
+                    Vec2::ZERO,
+                ) else {
+                    camper_arrival_broadcaster.write(CamperArrived::new(
+                        camper_info.get_viewer().clone(),
+                        *camper_logical_location,
+                    ));
+                    commands.entity(camper_entity).remove::<Pathfinding>();
+                    continue;
+                };
+
+                new_path_target.insert(next_path_target)
+            }
+        };
+
+        *camper_physical_location = path_target.advance(&time);
+        if !path_target.has_been_reached() {
+            if let Some(new_path_target) = new_path_target {
+                commands.entity(camper_entity).insert(new_path_target);
+            }
+
+            continue;
+        }
+
+        *camper_logical_location = path_target.get_logical_target();
+        if new_path_target.is_none() {
+            commands.entity(camper_entity).remove::<PathTarget>();
+        }
+    }
+}
+
+/// Moves campers on to the next part of their journey once they arrive,
+/// hiding them in the meadows and boarding them onto the bus.
+pub fn continue_camper_journey(
+    mut camper_arrivals: MessageReader<CamperArrived>,
+    mut campers: Query<(
+        Entity,
+        &CamperInformation,
+        &mut CamperJourney,
+        &mut Visibility,
+    )>,
+    mut commands: Commands,
+) {
+    for camper_arrival in camper_arrivals.read() {
+        let Some((camper_entity, _camper_info, mut camper_journey, mut camper_visibility)) =
+            campers
+                .iter_mut()
+                .find(|camper| camper.1.get_viewer() == camper_arrival.get_viewer())
+        else {
+            continue;
+        };
+
+        match *camper_journey {
+            CamperJourney::HeadingToMeadows => {
+                *camper_journey = CamperJourney::InMeadows;
+                *camper_visibility = Visibility::Hidden;
+            }
+            CamperJourney::HeadingToBus => {
+                commands.entity(camper_entity).despawn();
+            }
+            CamperJourney::InMeadows => {}
+        }
+    }
+}
+
+/// Sends campers from the meadows back to the bus once their scenario has
+/// been resolved, whether they succeeded or not.
+///
+/// A camper is spawned outside of the meadows for viewers without one.
+pub fn move_camper_to_bus(
+    mut scenario_attempts: MessageReader<ScenarioAttempt>,
+    meadow_location: Single<(&LogicalCoordinates, &Transform), With<MeadowEntrance>>,
+    outside_of_bus_location: Single<&LogicalCoordinates, With<OutsideOfBus>>,
+    mut camper_routes: CamperRoutes,
+    mut campers: Query<(
+        Entity,
+        &CamperInformation,
+        &mut CamperJourney,
+        &mut Visibility,
+    )>,
+    mut camper_sprites: CamperSprites,
+    mut commands: Commands,
+) {
+    let (meadow_logical_location, meadow_physical_location) = *meadow_location;
+    let mut campers_sent_back = HashSet::new();
+    for scenario_attempt in scenario_attempts.read() {
+        if !campers_sent_back.insert(scenario_attempt.get_viewer().clone()) {
+            continue;
+        }
+
+        let existing_camper = campers
+            .iter_mut()
+            .find(|camper| camper.1.get_viewer() == scenario_attempt.get_viewer());
+        // Campers still on the move are already where they need to be.
+        let is_camper_on_the_move = existing_camper
+            .as_ref()
+            .is_some_and(|camper| *camper.2 != CamperJourney::InMeadows);
+        if is_camper_on_the_move {
+            continue;
+        }
+
+        let path_to_bus = camper_routes.reserve_travel_path(
+            meadow_logical_location,
+            *outside_of_bus_location,
+            scenario_attempt.get_viewer(),
+        );
+
+        if let Some((camper_entity, _camper_info, mut camper_journey, mut camper_visibility)) =
+            existing_camper
+        {
+            *camper_journey = CamperJourney::HeadingToBus;
+            *camper_visibility = Visibility::Inherited;
+            commands.entity(camper_entity).insert(path_to_bus);
+            continue;
+        }
+
+        let mut camper_physical_location = *meadow_physical_location;
+        camper_physical_location.translation.z = CAMPER_DEPTH;
+
+        commands.spawn((
+            CamperBundle::new(
+                scenario_attempt.get_viewer(),
+                camper_physical_location,
+                *meadow_logical_location,
+                path_to_bus,
+                CamperJourney::HeadingToBus,
+            ),
+            camper_sprites.get_camper_sprite(),
         ));
     }
 }
+
+/// The bus while it is still parked at the campsite.
+type ParkedBus = (With<Bus>, Without<DepartingBus>);
+
+/// What is left to do at a campsite, which is either its objectives, or
+/// nothing at all when its objectives could not be loaded.
+type CampsiteWork = Or<(With<CamperObjective>, With<ObjectivesErrorMessage>)>;
+
+/// Sends the bus off to the exit once every objective has been completed
+/// and all campers are back on the bus.
+///
+/// A campsite whose objectives could not be loaded has nothing to do, so
+/// the bus drives on to the next one right away.
+pub fn start_bus_departure(
+    camper_objectives: Query<Option<&CamperObjective>, CampsiteWork>,
+    campers: Query<(), With<CamperInformation>>,
+    bus: Single<(Entity, &Transform), ParkedBus>,
+    outside_of_bus_location: Single<(&LogicalCoordinates, &Transform), With<OutsideOfBus>>,
+    bus_exit_location: Single<&LogicalCoordinates, With<BusExit>>,
+    traversal_graph: Single<&Graph>,
+    mut commands: Commands,
+) {
+    let campsite_completed = !camper_objectives.is_empty()
+        && camper_objectives.iter().all(|camper_objective| {
+            camper_objective.is_none_or(|camper_objective| camper_objective.is_completed())
+        });
+    if !campsite_completed || !campers.is_empty() {
+        return;
+    }
+
+    let (bus_entity, bus_physical_location) = *bus;
+    let (outside_of_bus_logical_location, outside_of_bus_physical_location) =
+        *outside_of_bus_location;
+    let path_to_exit = get_travel_path(
+        outside_of_bus_logical_location,
+        *bus_exit_location,
+        *traversal_graph,
+    );
+
+    let door_offset = bus_physical_location.translation.truncate()
+        - outside_of_bus_physical_location.translation.truncate();
+    commands.entity(bus_entity).insert((
+        path_to_exit,
+        DepartingBus::new(*outside_of_bus_logical_location, door_offset),
+    ));
+}
+
+/// The parts of the bus needed to drive it along its path.
+type DrivingBus<'a> = (
+    Entity,
+    &'a mut DepartingBus,
+    &'a mut Pathfinding,
+    &'a mut Transform,
+    Option<&'a mut PathTarget>,
+);
+
+/// Drives the bus along its path to the exit, letting everyone know
+/// the campsite has been cleared once it gets there.
+pub fn move_bus_to_exit(
+    bus: Single<DrivingBus, With<Bus>>,
+    path_targeting: PathTargeting,
+    time: Res<Time>,
+    mut campsite_cleared_broadcaster: MessageWriter<CampsiteCleared>,
+    mut commands: Commands,
+) {
+    let (bus_entity, mut departing_bus, mut travel_path, mut bus_physical_location, path_target) =
+        bus.into_inner();
+
+    let mut new_path_target = None;
+    let path_target = match path_target {
+        Some(path_target) => path_target.into_inner(),
+        None => {
+            let Some(next_path_target) = path_targeting.get_next_path_target(
+                &mut travel_path,
+                departing_bus.get_door_location(),
+                &bus_physical_location,
+                departing_bus.door_offset,
+            ) else {
+                campsite_cleared_broadcaster.write(CampsiteCleared);
+                commands.entity(bus_entity).remove::<Pathfinding>();
+                return;
+            };
+
+            new_path_target.insert(next_path_target)
+        }
+    };
+
+    *bus_physical_location = path_target.advance(&time);
+    if !path_target.has_been_reached() {
+        if let Some(new_path_target) = new_path_target {
+            commands.entity(bus_entity).insert(new_path_target);
+        }
+
+        return;
+    }
+
+    departing_bus.door_location = path_target.get_logical_target();
+    if new_path_target.is_none() {
+        commands.entity(bus_entity).remove::<PathTarget>();
+    }
+}
@@ -0,0 +1,230 @@
+use std::{io, net::SocketAddr, time::Duration};
+
+use futures_util::{SinkExt, StreamExt};
+use rand::Rng;
+use serde_json::json;
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+    time::{Instant, interval, sleep_until},
+};
+use tokio_tungstenite::{WebSocketStream, accept_async, tungstenite::Message};
+
+use crate::core_logic::clicking::ScriptedClick;
+
+/// How many different viewers random clicks come from.
+const NUM_RANDOM_VIEWERS: usize = 20;
+
+/// How often the mock server pings the game while it has no clicks to send,
+/// so that the game does not think the connection went quiet.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Where the clicks sent by the Mock Heat Server come from.
+#[derive(Clone, Debug)]
+pub enum MockClickFeed {
+    /// Replays the same click script to every game that connects.
+    Script(Vec<ScriptedClick>),
+    /// Sends a click at a random location from a random viewer once every interval.
+    Random(Duration),
+    /// Sends a click for every `<x> <y> [viewer id]` line typed into standard input.
+    Stdin,
+}
+
+/// A stand-in for the Heat API that speaks the same protocol, so that click
+/// handling can be tried out without a live Twitch extension.
+pub struct MockHeatServer {
+    listener: TcpListener,
+    click_feed: MockClickFeed,
+}
+
+/// Returns a click as it would be sent from the Heat API.
+fn get_click_response(viewer_id: &str, x: f32, y: f32) -> Message {
+    let click_response = json!({
+        "type": "click",
+        "id": viewer_id,
+        "x": x.to_string(),
+        "y": y.to_string(),
+    });
+
+    Message::text(click_response.to_string())
+}
+
+/// Returns the first message the Heat API sends after connecting.
+fn get_greeting_response() -> Message {
+    let greeting_response = json!({
+        "type": "system",
+        "message": "Connected to Heat API server.",
+    });
+
+    Message::text(greeting_response.to_string())
+}
+
+/// Returns the click typed in a line from standard input, or None if the
+/// line is not in the form of `<x> <y> [viewer id]`.
+fn parse_stdin_click(line: &str) -> Option<ScriptedClick> {
+    let mut fields = line.split_whitespace();
+    let x = fields.next()?.parse::<f32>().ok()?;
+    let y = fields.next()?.parse::<f32>().ok()?;
+
+    let mut stdin_click = ScriptedClick::new(Duration::ZERO, x, y);
+    if let Some(viewer_id) = fields.next() {
+        stdin_click.set_viewer_id(viewer_id.to_string());
+    }
+
+    if fields.next().is_some() {
+        return None;
+    }
+
+    Some(stdin_click)
+}
+
+/// Forwards every click typed into standard input to all connected games.
+async fn read_stdin_clicks(stdin_click_writer: broadcast::Sender<ScriptedClick>) {
+    let mut stdin_lines = BufReader::new(tokio::io::stdin()).lines();
+    while let Ok(Some(line)) = stdin_lines.next_line().await {
+        match parse_stdin_click(&line) {
+            Some(click) => {
+                // Nobody might be connected yet, which is fine.
+                let _ = stdin_click_writer.send(click);
+            }
+            None => println!(
+                "read_stdin_clicks: Expected '<x> <y> [viewer id]', got '{}'",
+                line
+            ),
+        }
+    }
+}
+
+/// Keeps the connection alive until the game disconnects.
+async fn wait_for_disconnect(connection: &mut WebSocketStream<TcpStream>) -> io::Result<()> {
+    let mut keep_alive = interval(KEEP_ALIVE_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = keep_alive.tick() => {
+                connection.send(Message::Ping(Vec::new().into())).await.map_err(io::Error::other)?;
+            }
+            response = connection.next() => {
+                if !matches!(response, Some(Ok(_))) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Sends clicks from the click feed to a single connected game.
+async fn serve_viewer_clicks(
+    stream: TcpStream,
+    click_feed: MockClickFeed,
+    mut stdin_clicks: broadcast::Receiver<ScriptedClick>,
+) -> io::Result<()> {
+    let mut connection = accept_async(stream).await.map_err(io::Error::other)?;
+    connection
+        .send(get_greeting_response())
+        .await
+        .map_err(io::Error::other)?;
+
+    match click_feed {
+        MockClickFeed::Script(scripted_clicks) => {
+            let started_at = Instant::now();
+            for scripted_click in scripted_clicks {
+                sleep_until(started_at + scripted_click.get_delay()).await;
+                let click_response = get_click_response(
+                    scripted_click.get_viewer_id(),
+                    scripted_click.get_x(),
+                    scripted_click.get_y(),
+                );
+                connection
+                    .send(click_response)
+                    .await
+                    .map_err(io::Error::other)?;
+            }
+
+            wait_for_disconnect(&mut connection).await
+        }
+        MockClickFeed::Random(time_between_clicks) => {
+            let mut click_timer = interval(time_between_clicks);
+            loop {
+                click_timer.tick().await;
+                let (viewer_id, x, y) = {
+                    let mut random_number_generator = rand::rng();
+                    let viewer_num = random_number_generator.random_range(0..NUM_RANDOM_VIEWERS);
+                    (
+                        format!("U{}", viewer_num),
+                        random_number_generator.random_range(0.0..1.0),
+                        random_number_generator.random_range(0.0..1.0),
+                    )
+                };
+                connection
+                    .send(get_click_response(&viewer_id, x, y))
+                    .await
+                    .map_err(io::Error::other)?;
+            }
+        }
+        MockClickFeed::Stdin => {
+            while let Ok(stdin_click) = stdin_clicks.recv().await {
+                let click_response = get_click_response(
+                    stdin_click.get_viewer_id(),
+                    stdin_click.get_x(),
+                    stdin_click.get_y(),
+                );
+                connection
+                    .send(click_response)
+                    .await
+                    .map_err(io::Error::other)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+impl MockHeatServer {
+    /// Returns a Mock Heat Server listening on the given address, such as
+    /// "127.0.0.1:8080". Port 0 picks any free port.
+    pub async fn bind(address: &str, click_feed: MockClickFeed) -> io::Result<Self> {
+        let listener = TcpListener::bind(address).await?;
+
+        Ok(Self {
+            listener,
+            click_feed,
+        })
+    }
+
+    pub fn get_address(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the base URL a Twitch Click Listener can connect to.
+    pub fn get_url(&self) -> io::Result<String> {
+        Ok(format!("ws://{}", self.get_address()?))
+    }
+
+    /// Accepts connections from games forever, sending each of them clicks
+    /// from the click feed.
+    pub async fn run(self) -> io::Result<()> {
+        let (stdin_click_writer, _) = broadcast::channel(64);
+        if matches!(self.click_feed, MockClickFeed::Stdin) {
+            tokio::spawn(read_stdin_clicks(stdin_click_writer.clone()));
+        }
+
+        loop {
+            let (stream, game_address) = self.listener.accept().await?;
+            println!("run: Game connected from {}", game_address);
+
+            let click_feed = self.click_feed.clone();
+            let stdin_clicks = stdin_click_writer.subscribe();
+            tokio::spawn(async move {
+                if let Err(connection_error) =
+                    serve_viewer_clicks(stream, click_feed, stdin_clicks).await
+                {
+                    println!(
+                        "run: Lost connection to {}: {}",
+                        game_address, connection_error
+                    );
+                }
+            });
+        }
+    }
+}
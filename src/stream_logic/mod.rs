@@ -1,4 +1,5 @@
 pub mod background_music;
+pub mod mock_heat_server;
 pub mod networking;
 
 use std::{path::PathBuf, time::Duration};
@@ -7,11 +8,163 @@ use background_music::{BackgroundPlayer, play_background_music, play_game_over_s
 use bevy::{prelude::*, window::WindowResolution};
 
 use crate::{
-    core_logic::{CampersState, CoreLogic, GameOverTime, MovementTime, progressing::HungerBarTime},
-    stream_logic::networking::{TwitchClickListener, map_twitch_clicks_to_uv},
+    core_logic::{
+        CampersState, CoreLogic, GameOverTime, MovementTime,
+        choosing::{TieBreaker, VotingTime},
+        clicking::{
+            ClickLimits, ClickSources, LocalMouseClickSource, ScriptedClickSource,
+            capture_local_mouse_clicks,
+        },
+        joining::{LobbyRules, StartTrip},
+        phrasing::Locale,
+        progressing::{
+            HungerBarTime, ObjectivesDirectory, Trip, TripProgress, get_objectives_error_message,
+        },
+    },
+    stream_logic::networking::{
+        HEAT_API_URL, HeatConnectionState, ReconnectionBackoff, TwitchClickListener,
+        report_dropped_heat_clicks, update_heat_connection_state,
+    },
 };
 
-pub struct StreamLogic;
+/// The places viewer clicks can come from while streaming.
+#[derive(Clone, Debug)]
+pub enum ClickSourceSettings {
+    /// Clicks from Twitch viewers through a Heat-compatible server found
+    /// at the given base URL, for the given channel id.
+    HeatApi { url: String, channel_id: String },
+    /// Clicks made with the mouse on the game window.
+    LocalMouse,
+    /// Clicks replayed from a click script file.
+    ScriptedFile(PathBuf),
+}
+
+pub struct StreamLogic {
+    click_sources: Vec<ClickSourceSettings>,
+    locale: Locale,
+    voting_time: VotingTime,
+    tie_breaker: TieBreaker,
+    lobby_rules: LobbyRules,
+}
+
+impl Default for StreamLogic {
+    fn default() -> Self {
+        let heat_api_source = ClickSourceSettings::HeatApi {
+            url: String::from(HEAT_API_URL),
+            channel_id: String::from("103834034"),
+        };
+        Self::new(vec![heat_api_source])
+    }
+}
+
+impl StreamLogic {
+    pub fn new(click_sources: Vec<ClickSourceSettings>) -> Self {
+        Self {
+            click_sources,
+            locale: Locale::default(),
+            voting_time: VotingTime::new(Duration::from_secs(20)),
+            tie_breaker: TieBreaker::Random,
+            lobby_rules: LobbyRules::default(),
+        }
+    }
+
+    /// Sets the language that the stream's campsites are shown in.
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Sets how long viewers have to vote on what a camper does.
+    pub fn set_voting_time(&mut self, voting_time: VotingTime) {
+        self.voting_time = voting_time;
+    }
+
+    /// Sets which choice wins when more than one has the most votes.
+    pub fn set_tie_breaker(&mut self, tie_breaker: TieBreaker) {
+        self.tie_breaker = tie_breaker;
+    }
+
+    /// Sets how long viewers have to join from the lobby.
+    pub fn set_lobby_wait_time(&mut self, wait_time: Duration) {
+        self.lobby_rules = LobbyRules::new(wait_time, self.lobby_rules.get_min_campers());
+    }
+
+    /// Sets how many campers have to join from the lobby before the trip
+    /// can begin.
+    pub fn set_min_campers(&mut self, min_campers: usize) {
+        self.lobby_rules = LobbyRules::new(self.lobby_rules.get_wait_time(), min_campers);
+    }
+}
+
+/// Spawns the camera the stream is seen through.
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+/// Lets the streamer begin the trip without waiting for the lobby by
+/// pressing Enter.
+fn start_trip_on_enter(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut start_trip_broadcaster: MessageWriter<StartTrip>,
+) {
+    if keys.just_pressed(KeyCode::Enter) {
+        start_trip_broadcaster.write(StartTrip);
+    }
+}
+
+/// Adds every configured Click Source to the game.
+///
+/// The Heat API and the local mouse are each expected at most once, since
+/// their systems and resources are only meant to be added once.
+fn add_click_sources(app: &mut App, click_source_settings: &[ClickSourceSettings]) {
+    let mut click_sources = ClickSources::default();
+
+    for click_source_setting in click_source_settings {
+        match click_source_setting {
+            ClickSourceSettings::HeatApi { url, channel_id } => {
+                let twitch_click_listener = TwitchClickListener::connect_to(
+                    url,
+                    channel_id,
+                    ReconnectionBackoff::default(),
+                );
+                app.insert_resource(twitch_click_listener.get_connection_monitor());
+                app.init_resource::<HeatConnectionState>();
+                app.add_systems(
+                    Update,
+                    (update_heat_connection_state, report_dropped_heat_clicks),
+                );
+
+                click_sources.add(twitch_click_listener);
+            }
+            ClickSourceSettings::LocalMouse => {
+                let (local_mouse_click_source, local_mouse_clicks) = LocalMouseClickSource::new();
+                app.insert_resource(local_mouse_clicks);
+                app.add_systems(Update, capture_local_mouse_clicks);
+
+                click_sources.add(local_mouse_click_source);
+            }
+            ClickSourceSettings::ScriptedFile(click_script_path) => {
+                click_sources.add(ScriptedClickSource::from_file(click_script_path));
+            }
+        }
+    }
+
+    app.insert_resource(click_sources);
+}
+
+/// Returns where some asset is found, which is relative to the project
+/// while developing, and relative to the game otherwise.
+fn get_asset_path(asset_path: &str) -> PathBuf {
+    let asset_path = PathBuf::from(asset_path);
+    #[cfg(debug_assertions)]
+    let asset_path = {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push(asset_path);
+
+        path
+    };
+
+    asset_path
+}
 
 impl Plugin for StreamLogic {
     fn build(&self, app: &mut App) {
@@ -37,22 +190,49 @@ impl Plugin for StreamLogic {
 
         // This section deals with how rooms are created in the game as the
         // explorer navigates the depths.
-        let core_logic = CoreLogic::new(movement_time, game_over_time, hunger_bar_time);
+        let core_logic = CoreLogic::new(
+            movement_time,
+            game_over_time,
+            hunger_bar_time,
+            self.voting_time.clone(),
+        );
         app.add_plugins(core_logic);
+        app.insert_resource(self.tie_breaker);
+
+        // This section limits how often viewers can click, so that nobody
+        // can take over the game by clicking as fast as they can.
+        let mut click_limits = ClickLimits::new(Duration::from_millis(250));
+        click_limits.set_max_clicks_per_second(60);
+        app.insert_resource(click_limits);
 
+        // Viewers join from a lobby for a minute, unless told otherwise or
+        // unless the streamer begins the trip sooner.
         app.insert_state(CampersState::Start);
+        app.insert_resource(self.lobby_rules.clone());
+        app.add_systems(
+            Update,
+            start_trip_on_enter.run_if(in_state(CampersState::Start)),
+        );
+        app.add_systems(Startup, spawn_camera);
 
-        // This section deals with all of the sounds and music heard during the game.
-        let music_path = PathBuf::from("assets/background_music/");
-        #[cfg(debug_assertions)]
-        let background_music_path = {
-            let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-            path.push(music_path);
+        // This section deals with which campsites the campers visit during their trip.
+        let objectives_path = get_asset_path("assets/objectives/");
+        app.insert_resource(ObjectivesDirectory::new(objectives_path));
+        app.insert_resource(self.locale.clone());
+        let trip_path = get_asset_path("assets/trips/trip.json");
+        match Trip::from_file(&trip_path) {
+            Ok(trip) => {
+                app.insert_resource(TripProgress::new(trip));
+            }
+            Err(trip_error) => {
+                error!("build: {}", trip_error);
+                app.world_mut()
+                    .spawn(get_objectives_error_message(trip_error, &self.locale));
+            }
+        }
 
-            path
-        };
-        #[cfg(not(debug_assertions))]
-        let background_music_path = music_path;
+        // This section deals with all of the sounds and music heard during the game.
+        let background_music_path = get_asset_path("assets/background_music/");
         app.insert_resource(BackgroundPlayer::new(&background_music_path));
 
         let sound_and_music_systems =
@@ -65,8 +245,6 @@ impl Plugin for StreamLogic {
 
         // This section deals with how interactions are handled in the game
         // from outside sources, mainly from Twitch.
-
-        app.insert_resource(TwitchClickListener::connect("103834034"));
-        app.add_systems(Update, map_twitch_clicks_to_uv);
+        add_click_sources(app, &self.click_sources);
     }
 }
@@ -1,62 +1,181 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use futures_util::StreamExt;
 use futures_util::stream::SplitStream;
 use tokio::net::TcpStream;
-use tokio::time::timeout;
+use tokio::time::{sleep, timeout};
 
-use crate::core_logic::interacting::ViewerClick;
+use crate::core_logic::{
+    clicking::ClickSource,
+    interacting::{ViewerClick, ViewerIdentity},
+};
 use bevy::prelude::*;
+use rand::Rng;
 use serde_json::Value;
 use tokio::runtime::Runtime;
-use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::sync::mpsc::{self, Receiver, Sender, error::TrySendError};
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
 
-#[derive(Resource)]
+/// Where the Heat API lives when streaming for real.
+pub const HEAT_API_URL: &str = "wss://heat-api.j38.net";
+
+/// How many clicks from the Heat API can wait for the game before any
+/// more are dropped.
+pub const DEFAULT_CLICK_CAPACITY: NonZeroUsize = NonZeroUsize::new(256).unwrap();
+
+type HeatConnectionReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+
+/// Represents how the connection to the Heat API is doing, so that
+/// the game can let viewers know when their clicks are not coming through.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatConnectionState {
+    #[default]
+    Connecting,
+    Connected,
+    /// Waiting to try connecting again, along with how many attempts
+    /// have been made since the last successful connection.
+    Reconnecting(usize),
+    /// No more attempts will be made to connect to the Heat API.
+    Failed,
+}
+
+/// Determines how long to wait before trying to connect to the Heat API
+/// again, doubling the wait after every failed attempt.
+#[derive(Clone, Debug)]
+pub struct ReconnectionBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_attempts: Option<usize>,
+}
+
+impl Default for ReconnectionBackoff {
+    fn default() -> Self {
+        let initial_delay = Duration::from_secs(1);
+        let max_delay = Duration::from_secs(60);
+        Self::new(initial_delay, max_delay)
+    }
+}
+
+impl ReconnectionBackoff {
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            max_attempts: None,
+        }
+    }
+
+    /// Sets how many reconnection attempts in a row can fail before giving up.
+    ///
+    /// Without this, reconnecting is attempted forever.
+    pub fn set_max_attempts(&mut self, max_attempts: usize) {
+        self.max_attempts = Some(max_attempts);
+    }
+
+    /// Returns the delay before the given reconnection attempt, excluding jitter.
+    pub fn get_delay(&self, attempt: usize) -> Duration {
+        let doublings = attempt.saturating_sub(1).min(u32::MAX as usize) as u32;
+        let multiplier = 2u32.checked_pow(doublings).unwrap_or(u32::MAX);
+
+        self.initial_delay
+            .checked_mul(multiplier)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Returns the delay before the given reconnection attempt, with up to half
+    /// of it randomized so that many listeners do not reconnect all at once.
+    pub fn get_jittered_delay(&self, attempt: usize) -> Duration {
+        let delay = self.get_delay(attempt);
+        let half_delay = delay / 2;
+
+        let mut random_number_generator = rand::rng();
+        let jitter_fraction = random_number_generator.random_range(0.0..=1.0);
+        half_delay + half_delay.mul_f64(jitter_fraction)
+    }
+
+    /// Returns whether no more reconnection attempts should be made.
+    pub fn has_given_up(&self, attempt: usize) -> bool {
+        self.max_attempts
+            .is_some_and(|max_attempts| attempt > max_attempts)
+    }
+}
+
+/// Represents the reasons a connection to the Heat API could not be made.
+#[derive(Debug)]
+pub enum HeatConnectionError {
+    CannotConnect(tokio_tungstenite::tungstenite::Error),
+    NoGreeting,
+    UnexpectedGreeting(Value),
+}
+
+impl fmt::Display for HeatConnectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeatConnectionError::CannotConnect(error) => {
+                write!(f, "Cannot connect to heat URL: {}", error)
+            }
+            HeatConnectionError::NoGreeting => write!(f, "Did not get a system response."),
+            HeatConnectionError::UnexpectedGreeting(response) => {
+                write!(f, "Unexpected system response: {}", response)
+            }
+        }
+    }
+}
+
 pub struct TwitchClickListener {
     _rt: Option<Runtime>,
-    message_receiver: UnboundedReceiver<ViewerClick>,
+    message_receiver: Receiver<ViewerClick>,
+    connection_state: watch::Receiver<HeatConnectionState>,
+    num_dropped_clicks: Arc<AtomicUsize>,
 }
 
-async fn read_heat_response(
-    connection: &mut SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
-) -> Option<Value> {
+/// Returns the next JSON message sent from the Heat API, skipping anything
+/// that is not JSON, or None when the connection has gone quiet or closed.
+async fn read_heat_response(connection: &mut HeatConnectionReader) -> Option<Value> {
     let response_timeout = Duration::from_secs(30);
 
-    let response = timeout(response_timeout, connection.next())
-        .await
-        .ok()??
-        .ok()?;
-    let response_text = response
-        .into_text()
-        .expect("read_heat_response: Could not convert response to text.");
-    let response_text = response_text.as_str();
-    let response_json = serde_json::from_str::<Value>(response_text)
-        .expect("read_heat_response: Response is not in JSON.");
-    Some(response_json)
+    loop {
+        let response = timeout(response_timeout, connection.next())
+            .await
+            .ok()??
+            .ok()?;
+        let response_text = match response {
+            Message::Text(response_text) => response_text,
+            Message::Close(_) => return None,
+            _ => continue,
+        };
+
+        if let Ok(response_json) = serde_json::from_str::<Value>(response_text.as_str()) {
+            return Some(response_json);
+        }
+    }
 }
 
 async fn verify_connected_to_heat_api(
-    connection: &mut SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
-) {
+    connection: &mut HeatConnectionReader,
+) -> Result<(), HeatConnectionError> {
     let response_json = read_heat_response(connection)
         .await
-        .expect("verify_connected_to_heat_api: Could not read system response.");
+        .ok_or(HeatConnectionError::NoGreeting)?;
     let valid_response = response_json["type"] == "system"
         && response_json["message"] == "Connected to Heat API server.";
     if !valid_response {
-        panic!("verify_connected_to_heat_api: Did not get system response.");
+        return Err(HeatConnectionError::UnexpectedGreeting(response_json));
     }
-}
 
-async fn read_click_event(
-    connection: &mut SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
-) -> Option<ViewerClick> {
-    let json_response = read_heat_response(connection).await?;
-    get_viewer_click_from_response(json_response)
+    Ok(())
 }
 
-fn get_viewer_click_from_response(json_response: Value) -> Option<ViewerClick> {
+/// Returns the click sent in a response from the Heat API, or None if the
+/// response is not a click.
+pub fn get_viewer_click_from_response(json_response: Value) -> Option<ViewerClick> {
     if json_response.get("type")?.as_str()? != "click" {
         return None;
     }
@@ -64,53 +183,202 @@ fn get_viewer_click_from_response(json_response: Value) -> Option<ViewerClick> {
     let uv_x = json_response.get("x")?.as_str()?.parse::<f32>().ok()?;
     let uv_y = json_response.get("y")?.as_str()?.parse::<f32>().ok()?;
 
-    Some(ViewerClick::new(uv_x, 1.0 - uv_y))
+    // Heat sends the viewer's Twitch user id if they shared their identity,
+    // or an opaque id otherwise. Either one tells viewers apart, but a click
+    // without any id still counts, just for nobody in particular.
+    let mut viewer = match json_response.get("id").and_then(Value::as_str) {
+        Some(viewer_id) => ViewerIdentity::new(viewer_id.to_string()),
+        None => ViewerIdentity::anonymous(),
+    };
+
+    if let Some(login) = json_response.get("login").and_then(Value::as_str) {
+        viewer.set_login(login.to_string());
+    }
+
+    if let Some(display_name) = json_response.get("display_name").and_then(Value::as_str) {
+        viewer.set_display_name(display_name.to_string());
+    }
+
+    Some(ViewerClick::new(viewer, uv_x, 1.0 - uv_y))
 }
 
 async fn connect_to_heat_api(
-    twitch_channel_id: String,
-) -> SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-    let connection_url = format!("wss://heat-api.j38.net/channel/{}", twitch_channel_id);
+    heat_api_url: &str,
+    twitch_channel_id: &str,
+) -> Result<HeatConnectionReader, HeatConnectionError> {
+    let connection_url = format!(
+        "{}/channel/{}",
+        heat_api_url.trim_end_matches('/'),
+        twitch_channel_id
+    );
     let (connection, _) = connect_async(&connection_url)
         .await
-        .expect("connect: Cannot connect to heat URL.");
+        .map_err(HeatConnectionError::CannotConnect)?;
     let (_, mut connection_reader) = connection.split();
-    verify_connected_to_heat_api(&mut connection_reader).await;
+    verify_connected_to_heat_api(&mut connection_reader).await?;
 
     println!("connect_to_heat_api: Successfully connected to heat endpoint.");
 
-    connection_reader
+    Ok(connection_reader)
+}
+
+/// Forwards clicks from the Heat API to the game, reconnecting with
+/// backoff whenever the connection fails or drops.
+///
+/// Clicks arriving while the game has too many waiting are dropped
+/// instead, so that a flood of clicks never backs up the connection.
+async fn listen_for_clicks(
+    heat_api_url: String,
+    twitch_channel_id: String,
+    backoff: ReconnectionBackoff,
+    message_writer: Sender<ViewerClick>,
+    connection_state_writer: watch::Sender<HeatConnectionState>,
+    num_dropped_clicks: Arc<AtomicUsize>,
+) {
+    let mut attempt = 0;
+    loop {
+        if attempt == 0 {
+            connection_state_writer.send_replace(HeatConnectionState::Connecting);
+        } else {
+            connection_state_writer.send_replace(HeatConnectionState::Reconnecting(attempt));
+        }
+
+        match connect_to_heat_api(&heat_api_url, &twitch_channel_id).await {
+            Ok(mut connection_reader) => {
+                attempt = 0;
+                connection_state_writer.send_replace(HeatConnectionState::Connected);
+
+                while let Some(response) = read_heat_response(&mut connection_reader).await {
+                    let Some(click_event) = get_viewer_click_from_response(response) else {
+                        continue;
+                    };
+
+                    match message_writer.try_send(click_event) {
+                        Ok(()) => {}
+                        Err(TrySendError::Full(_click_event)) => {
+                            num_dropped_clicks.fetch_add(1, Ordering::Relaxed);
+                        }
+                        // The game is no longer listening, so there is nobody
+                        // left to reconnect for.
+                        Err(TrySendError::Closed(_click_event)) => return,
+                    }
+                }
+
+                println!("listen_for_clicks: Lost connection to heat endpoint.");
+            }
+            Err(connection_error) => {
+                println!("listen_for_clicks: {}", connection_error);
+            }
+        }
+
+        attempt += 1;
+        if backoff.has_given_up(attempt) {
+            connection_state_writer.send_replace(HeatConnectionState::Failed);
+            println!("listen_for_clicks: Giving up on connecting to heat endpoint.");
+            return;
+        }
+
+        connection_state_writer.send_replace(HeatConnectionState::Reconnecting(attempt));
+        sleep(backoff.get_jittered_delay(attempt)).await;
+    }
 }
 
 impl TwitchClickListener {
     pub fn connect(channel_id: &str) -> Self {
+        Self::connect_with_backoff(channel_id, ReconnectionBackoff::default())
+    }
+
+    pub fn connect_with_backoff(channel_id: &str, backoff: ReconnectionBackoff) -> Self {
+        Self::connect_to(HEAT_API_URL, channel_id, backoff)
+    }
+
+    /// Returns a listener for clicks from a Heat-compatible server found at the
+    /// given base URL, which can be either `wss://` or plain `ws://`.
+    pub fn connect_to(heat_api_url: &str, channel_id: &str, backoff: ReconnectionBackoff) -> Self {
+        Self::connect_with_capacity(heat_api_url, channel_id, backoff, DEFAULT_CLICK_CAPACITY)
+    }
+
+    /// Returns a listener like `connect_to`, holding at most the given number
+    /// of clicks for the game before dropping new ones.
+    ///
+    /// The capacity can never be 0, since no click could ever reach the game.
+    pub fn connect_with_capacity(
+        heat_api_url: &str,
+        channel_id: &str,
+        backoff: ReconnectionBackoff,
+        click_capacity: NonZeroUsize,
+    ) -> Self {
         let _rt = tokio::runtime::Builder::new_multi_thread()
             .enable_all()
             .enable_time()
             .build()
             .expect("connect: Could not prepare networking for click listening.");
 
+        let heat_api_url = String::from(heat_api_url);
         let twitch_channel_id = String::from(channel_id);
-        let (message_writer, message_receiver) = mpsc::unbounded_channel();
-        _rt.spawn(async move {
-            loop {
-                let mut connection_reader = connect_to_heat_api(twitch_channel_id.clone()).await;
-                while let Some(click_event) = read_click_event(&mut connection_reader).await {
-                    message_writer
-                        .send(click_event)
-                        .expect("Cannot broadcast viewer clicks anymore");
-                }
-            }
-        });
+        let (message_writer, message_receiver) = mpsc::channel(click_capacity.get());
+        let (connection_state_writer, connection_state) =
+            watch::channel(HeatConnectionState::default());
+        let num_dropped_clicks = Arc::new(AtomicUsize::new(0));
+        _rt.spawn(listen_for_clicks(
+            heat_api_url,
+            twitch_channel_id,
+            backoff,
+            message_writer,
+            connection_state_writer,
+            num_dropped_clicks.clone(),
+        ));
 
         Self {
             _rt: Some(_rt),
             message_receiver,
+            connection_state,
+            num_dropped_clicks,
+        }
+    }
+
+    /// Returns a monitor that follows the state of this listener's connection.
+    pub fn get_connection_monitor(&self) -> HeatConnectionMonitor {
+        HeatConnectionMonitor {
+            connection_state: self.connection_state.clone(),
+            num_dropped_clicks: self.num_dropped_clicks.clone(),
         }
     }
+}
+
+impl ClickSource for TwitchClickListener {
+    fn read(&mut self) -> Option<ViewerClick> {
+        let twitch_click = self.message_receiver.try_recv().ok()?;
+        println!(
+            "{}: Received click event from {}. x = {}, y = {}",
+            chrono::Local::now(),
+            twitch_click.get_viewer().get_id(),
+            twitch_click.get_x(),
+            twitch_click.get_y()
+        );
+
+        Some(twitch_click)
+    }
+}
+
+/// Follows the state of a Twitch Click Listener's connection, even after
+/// the listener has been handed over to the game's Click Sources.
+#[derive(Resource)]
+pub struct HeatConnectionMonitor {
+    connection_state: watch::Receiver<HeatConnectionState>,
+    num_dropped_clicks: Arc<AtomicUsize>,
+}
 
-    pub fn read(&mut self) -> Option<ViewerClick> {
-        self.message_receiver.try_recv().ok()
+impl HeatConnectionMonitor {
+    /// Returns the latest known state of the connection to the Heat API.
+    pub fn get_connection_state(&self) -> HeatConnectionState {
+        *self.connection_state.borrow()
+    }
+
+    /// Returns how many clicks were dropped because the game already had
+    /// too many waiting.
+    pub fn get_num_dropped_clicks(&self) -> usize {
+        self.num_dropped_clicks.load(Ordering::Relaxed)
     }
 }
 
@@ -128,17 +396,39 @@ impl Drop for TwitchClickListener {
     }
 }
 
-pub fn map_twitch_clicks_to_uv(
-    mut twitch_click_listener: ResMut<TwitchClickListener>,
-    mut viewer_click_broadcaster: MessageWriter<ViewerClick>,
+/// Keeps the Heat connection state resource in sync with the click listener.
+pub fn update_heat_connection_state(
+    heat_connection_monitor: Res<HeatConnectionMonitor>,
+    mut heat_connection_state: ResMut<HeatConnectionState>,
 ) {
-    while let Some(twitch_click) = twitch_click_listener.read() {
-        println!(
-            "{}: Received click event. x = {}, y = {}",
-            chrono::Local::now(),
-            twitch_click.get_x(),
-            twitch_click.get_y()
-        );
-        viewer_click_broadcaster.write(twitch_click);
+    let latest_connection_state = heat_connection_monitor.get_connection_state();
+    if *heat_connection_state == latest_connection_state {
+        return;
     }
+
+    println!(
+        "{}: Heat connection state changed to {:?}",
+        chrono::Local::now(),
+        latest_connection_state
+    );
+    *heat_connection_state = latest_connection_state;
+}
+
+/// Lets the streamer know whenever clicks from the Heat API had to be
+/// dropped because the game could not keep up.
+pub fn report_dropped_heat_clicks(
+    heat_connection_monitor: Res<HeatConnectionMonitor>,
+    mut num_reported_drops: Local<usize>,
+) {
+    let num_dropped_clicks = heat_connection_monitor.get_num_dropped_clicks();
+    if num_dropped_clicks == *num_reported_drops {
+        return;
+    }
+
+    warn!(
+        "report_dropped_heat_clicks: Dropped {} Heat clicks waiting for the game, {} in total.",
+        num_dropped_clicks - *num_reported_drops,
+        num_dropped_clicks
+    );
+    *num_reported_drops = num_dropped_clicks;
 }
@@ -0,0 +1,290 @@
+use std::{path::PathBuf, time::Duration};
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    choosing::{
+        ChoiceBox, ChoiceVoteCount, ScenarioPanel, ScenarioPanelDescription, ScenarioPanelTitle,
+        ScenarioVotes, TieBreaker, VotingCountdownBar, VotingTime, get_clicked_choice,
+    },
+    interacting::{CamperInformation, ObjectiveAttempt, ViewerClick, ViewerIdentity},
+    phrasing::Locale,
+    progressing::{CamperObjective, Landmark, ObjectivesDirectory},
+    resolving::{GameRng, ScenarioChoiceMade},
+    setting::WorldTileDimensions,
+    traveling::{CamperJourney, Pathfinding},
+};
+
+use bevy::prelude::*;
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(game: &mut MockGame, locale_code: String) {
+    *game.get_resource_mut::<Locale>() = Locale::new(locale_code);
+}
+
+#[given(regex = r"the objectives directory '(.+)',")]
+fn given_objectives_directory(game: &mut MockGame, objectives_directory_name: String) {
+    let mut objectives_directory_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    objectives_directory_path.push("tests/assets/");
+    objectives_directory_path.push(objectives_directory_name);
+
+    *game.get_resource_mut::<ObjectivesDirectory>() =
+        ObjectivesDirectory::new(objectives_directory_path);
+}
+
+#[given(regex = r"the voting window lasts (\d+) seconds,")]
+fn given_voting_window(game: &mut MockGame, num_seconds: u64) {
+    *game.get_resource_mut::<VotingTime>() = VotingTime::new(Duration::from_secs(num_seconds));
+}
+
+#[given(regex = r"ties are broken (by the first listed|by the first voted|at random),")]
+fn given_tie_breaker(game: &mut MockGame, tie_breaker_name: String) {
+    let tie_breaker = match tie_breaker_name.as_str() {
+        "by the first listed" => TieBreaker::FirstListed,
+        "by the first voted" => TieBreaker::FirstVoted,
+        _ => TieBreaker::Random,
+    };
+
+    *game.get_resource_mut::<TieBreaker>() = tie_breaker;
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[when(regex = r"'(.+)' attempts the (\d+)[a-z]+ objective,")]
+fn when_player_attempts_objective(game: &mut MockGame, player_name: String, objective_num: usize) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    let objective_name = camper_objective.get_name();
+
+    let player = ViewerIdentity::new(player_name);
+    game.broadcast(ObjectiveAttempt::new(player, objective_name));
+}
+
+#[when(regex = r"'(.+)' finishes traveling,")]
+fn when_player_finishes_traveling(game: &mut MockGame, player_name: String) {
+    // Campers move one tile per tick in tests, so no path should ever
+    // take longer than visiting every tile on the map.
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+
+    for _i in 0..max_ticks {
+        let camper_still_traveling = game
+            .get_all_containing::<Pathfinding, CamperInformation>()
+            .iter()
+            .any(|camper| *camper.1.get_camper_name() == player_name);
+        if !camper_still_traveling {
+            // One more tick for the scenario panel to appear.
+            game.tick();
+            return;
+        }
+
+        game.tick();
+    }
+
+    panic!("when_player_finishes_traveling: Camper never finished traveling.");
+}
+
+#[when(regex = r"'(.+)' clicks on the (\d+)[a-z]+ choice,")]
+fn when_viewer_clicks_choice(game: &mut MockGame, viewer_name: String, choice_num: usize) {
+    let num_choices = game.get_all::<ChoiceBox>().len();
+    // Choices split the width of the panel evenly, so the middle of
+    // each one is found halfway through its share.
+    let uv_x = (choice_num as f32 - 0.5) / num_choices as f32;
+    let uv_y = 0.1;
+
+    let viewer = ViewerIdentity::new(viewer_name);
+    game.broadcast(ViewerClick::new(viewer, uv_x, uv_y));
+}
+
+#[when(regex = r"(\d+) seconds of voting pass,")]
+fn when_voting_time_passes(game: &mut MockGame, num_seconds: u64) {
+    game.pass_time(Duration::from_secs(num_seconds));
+}
+
+#[when("the voting window closes,")]
+fn when_voting_window_closes(game: &mut MockGame) {
+    let voting_duration = game.get_resource::<VotingTime>().get_timer().duration();
+    game.pass_time(voting_duration);
+}
+
+#[when(regex = r"'(.+)' clicks at ([\d.]+), ([\d.]+),")]
+fn when_viewer_clicks_at(game: &mut MockGame, viewer_name: String, uv_x: f32, uv_y: f32) {
+    let viewer = ViewerIdentity::new(viewer_name);
+    game.broadcast(ViewerClick::new(viewer, uv_x, uv_y));
+}
+
+#[then("the scenario panel should be shown.")]
+fn verify_scenario_panel_shown(game: &mut MockGame) {
+    assert_eq!(game.get_all::<ScenarioPanel>().len(), 1);
+}
+
+#[then("the scenario panel should not be shown.")]
+fn verify_scenario_panel_not_shown(game: &mut MockGame) {
+    assert!(game.get_all::<ScenarioPanel>().is_empty());
+}
+
+#[then(regex = r"the scenario panel should be titled '(.+)'")]
+fn verify_scenario_panel_title(game: &mut MockGame, expected_title: String) {
+    let (title_text, _marker) = game.get_all_containing::<Text, ScenarioPanelTitle>()[0];
+    assert_eq!(title_text.0, expected_title);
+}
+
+#[then(regex = r"the scenario panel should describe '(.+)'")]
+fn verify_scenario_panel_description(game: &mut MockGame, expected_description: String) {
+    let (description_text, _marker) =
+        game.get_all_containing::<Text, ScenarioPanelDescription>()[0];
+    assert!(
+        description_text.0.contains(&expected_description),
+        "{}",
+        description_text.0
+    );
+}
+
+#[then(regex = r"the scenario panel should have (\d+) choices.")]
+fn verify_num_choice_boxes(game: &mut MockGame, expected_num_choices: usize) {
+    assert_eq!(game.get_all::<ChoiceBox>().len(), expected_num_choices);
+}
+
+#[then(regex = r"the (\d+)[a-z]+ choice box should read '(.+)'")]
+fn verify_choice_box_text(game: &mut MockGame, choice_num: usize, expected_text: String) {
+    let choice_boxes = game.get_all_containing::<Text, ChoiceBox>();
+    let (choice_text, _choice_box) = choice_boxes
+        .iter()
+        .find(|(_text, choice_box)| choice_box.get_choice_num() == choice_num - 1)
+        .expect("verify_choice_box_text: Could not find the choice box.");
+
+    assert_eq!(choice_text.0, expected_text);
+}
+
+#[then(regex = r"the (\d+)[a-z]+ choice box should show '(.+)'.")]
+fn verify_choice_vote_count(game: &mut MockGame, choice_num: usize, expected_text: String) {
+    let vote_counts = game.get_all_containing::<TextSpan, ChoiceVoteCount>();
+    let (vote_count_text, _vote_count) = vote_counts
+        .iter()
+        .find(|(_text, vote_count)| vote_count.get_choice_num() == choice_num - 1)
+        .expect("verify_choice_vote_count: Could not find the vote count.");
+
+    assert_eq!(vote_count_text.0.trim(), expected_text);
+}
+
+#[then(regex = r"the countdown bar should be (\d+)% full.")]
+fn verify_countdown_bar(game: &mut MockGame, expected_percentage: f32) {
+    let (countdown_bar, _marker) = game.get_all_containing::<Node, VotingCountdownBar>()[0];
+    let Val::Percent(percentage) = countdown_bar.width else {
+        panic!("verify_countdown_bar: The countdown bar is not sized by percentage.");
+    };
+
+    // Real time still passes between ticks, so allow a little leeway.
+    assert!(
+        (percentage - expected_percentage).abs() < 1.0,
+        "The countdown bar is {}% full.",
+        percentage
+    );
+}
+
+#[then(regex = r"'(.+)' should have gone with the (\d+)[a-z]+ choice with (\d+) votes.")]
+fn verify_choice_made(
+    game: &mut MockGame,
+    player_name: String,
+    choice_num: usize,
+    expected_num_votes: usize,
+) {
+    let player = ViewerIdentity::new(player_name);
+    let scenario_choice_made = game
+        .get_messages::<ScenarioChoiceMade>()
+        .into_iter()
+        .find(|scenario_choice_made| *scenario_choice_made.get_viewer() == player)
+        .cloned()
+        .expect("verify_choice_made: No choice was made for the player.");
+
+    let expected_choice = game
+        .get_all::<Landmark>()
+        .iter()
+        .flat_map(|landmark| landmark.get_scenarios())
+        .find(|scenario| scenario.get_type() == *scenario_choice_made.get_objective())
+        .expect("verify_choice_made: Could not find the scenario.")
+        .get_choice(choice_num - 1)
+        .clone();
+
+    assert_eq!(*scenario_choice_made.get_choice(), expected_choice);
+    assert_eq!(scenario_choice_made.get_num_votes(), expected_num_votes);
+}
+
+#[then(
+    regex = r"a tie between (\d+) choices broken at random with the seed (\d+) should go the same way every time."
+)]
+fn verify_random_tie_reproducible(_game: &mut MockGame, num_choices: usize, seed: u64) {
+    let mut scenario_votes = ScenarioVotes::new(&VotingTime::new(Duration::from_secs(1)));
+    for choice_num in 0..num_choices {
+        let viewer = ViewerIdentity::new(format!("Viewer {}", choice_num + 1));
+        scenario_votes.vote(viewer, choice_num);
+    }
+
+    let winning_choices = (0..2)
+        .map(|_run| {
+            let mut game_rng = GameRng::from_seed(seed);
+            scenario_votes.get_winning_choice(TieBreaker::Random, &mut game_rng)
+        })
+        .collect::<Vec<Option<usize>>>();
+
+    assert!(winning_choices[0].is_some());
+    assert_eq!(winning_choices[0], winning_choices[1]);
+}
+
+/// Returns where the camper of some player is on their journey.
+fn get_camper_journey(game: &mut MockGame, player_name: &str) -> CamperJourney {
+    let (camper_journey, _camper_info) = *game
+        .get_all_containing::<CamperJourney, CamperInformation>()
+        .iter()
+        .find(|(_journey, camper_info)| *camper_info.get_camper_name() == player_name)
+        .expect("get_camper_journey: Could not find the camper.");
+
+    *camper_journey
+}
+
+#[then(regex = r"the camper for '(.+)' should be heading back to the bus.")]
+fn verify_camper_heading_to_bus(game: &mut MockGame, player_name: String) {
+    let camper_journey = get_camper_journey(game, &player_name);
+    assert_eq!(camper_journey, CamperJourney::HeadingToBus);
+}
+
+#[then(regex = r"the camper for '(.+)' should still be in the meadows.")]
+fn verify_camper_in_meadows(game: &mut MockGame, player_name: String) {
+    let camper_journey = get_camper_journey(game, &player_name);
+    assert_eq!(camper_journey, CamperJourney::InMeadows);
+}
+
+#[then(
+    regex = r"a click at ([\d.]+), ([\d.]+) with (\d+) choices should land on (choice (\d+)|no choice)."
+)]
+fn verify_clicked_choice(
+    _game: &mut MockGame,
+    uv_x: f32,
+    uv_y: f32,
+    num_choices: usize,
+    _landing_spot: String,
+    expected_choice_num: String,
+) {
+    let viewer_click = ViewerClick::new(ViewerIdentity::default(), uv_x, uv_y);
+    let expected_choice = expected_choice_num
+        .parse::<usize>()
+        .ok()
+        .map(|choice_num| choice_num - 1);
+
+    assert_eq!(
+        get_clicked_choice(&viewer_click, num_choices),
+        expected_choice
+    );
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/choosing.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
@@ -0,0 +1,194 @@
+use std::{path::PathBuf, time::Duration};
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    clicking::{
+        ChannelClickSource, ClickLimiter, ClickLimits, ClickSources, ScriptedClick,
+        ScriptedClickSource,
+    },
+    interacting::{TileClicked, ViewerClick, ViewerIdentity},
+    setting::LogicalCoordinates,
+};
+
+/// Sends a click through the click channel without waiting for the game
+/// to receive it.
+fn send_through_click_channel(game: &mut MockGame, viewer_id: String, x: f32, y: f32) {
+    let viewer = ViewerIdentity::new(viewer_id);
+    let click_sent = game
+        .click_sender
+        .as_ref()
+        .expect("send_through_click_channel: No click channel was given.")
+        .send(ViewerClick::new(viewer, x, y));
+    assert!(click_sent);
+}
+
+#[given(regex = r"the campsite map '(.+)' has been rendered,")]
+fn given_rendered_campsite_map(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[given("a click channel,")]
+fn given_click_channel(game: &mut MockGame) {
+    let (click_source, click_sender) = ChannelClickSource::new();
+    game.get_resource_mut::<ClickSources>().add(click_source);
+    game.click_sender = Some(click_sender);
+}
+
+#[given(regex = r"viewers have to wait (\d+) seconds? between clicks,")]
+fn given_viewer_cooldown(game: &mut MockGame, cooldown_secs: u64) {
+    let max_clicks_per_second = game
+        .get_resource::<ClickLimits>()
+        .get_max_clicks_per_second();
+    let mut click_limits = ClickLimits::new(Duration::from_secs(cooldown_secs));
+    if let Some(max_clicks_per_second) = max_clicks_per_second {
+        click_limits.set_max_clicks_per_second(max_clicks_per_second);
+    }
+
+    *game.get_resource_mut::<ClickLimits>() = click_limits;
+}
+
+#[given(regex = r"the game accepts at most (\d+) clicks per second,")]
+fn given_max_clicks_per_second(game: &mut MockGame, max_clicks_per_second: usize) {
+    game.get_resource_mut::<ClickLimits>()
+        .set_max_clicks_per_second(max_clicks_per_second);
+}
+
+#[given(regex = r"a click script with a click after (\d+) seconds at ([0-9.]+), ([0-9.]+),")]
+fn given_click_script(game: &mut MockGame, delay_secs: u64, x: f32, y: f32) {
+    let scripted_click = ScriptedClick::new(Duration::from_secs(delay_secs), x, y);
+    let click_source = ScriptedClickSource::new(vec![scripted_click]);
+    game.get_resource_mut::<ClickSources>().add(click_source);
+}
+
+#[given(
+    regex = r"a click script with a click after (\d+) seconds at ([0-9.]+), ([0-9.]+) from '(.+)',"
+)]
+fn given_click_script_from_viewer(
+    game: &mut MockGame,
+    delay_secs: u64,
+    x: f32,
+    y: f32,
+    viewer_id: String,
+) {
+    let mut scripted_click = ScriptedClick::new(Duration::from_secs(delay_secs), x, y);
+    scripted_click.set_viewer_id(viewer_id);
+    let click_source = ScriptedClickSource::new(vec![scripted_click]);
+    game.get_resource_mut::<ClickSources>().add(click_source);
+}
+
+#[when(regex = r"'(.+)' clicks at ([0-9.]+), ([0-9.]+) through the click channel,")]
+fn when_viewer_clicks_through_channel(game: &mut MockGame, viewer_id: String, x: f32, y: f32) {
+    send_through_click_channel(game, viewer_id, x, y);
+
+    game.tick();
+}
+
+#[when(regex = r"'(.+)' clicks at ([0-9.]+), ([0-9.]+) through the click channel (\d+) times,")]
+fn when_viewer_clicks_through_channel_repeatedly(
+    game: &mut MockGame,
+    viewer_id: String,
+    x: f32,
+    y: f32,
+    num_clicks: usize,
+) {
+    for _i in 0..num_clicks {
+        send_through_click_channel(game, viewer_id.clone(), x, y);
+    }
+
+    game.tick();
+}
+
+#[when(regex = r"(\d+) viewers click at ([0-9.]+), ([0-9.]+) through the click channel,")]
+fn when_viewers_click_through_channel(game: &mut MockGame, num_viewers: usize, x: f32, y: f32) {
+    for viewer_num in 1..=num_viewers {
+        send_through_click_channel(game, format!("Viewer {}", viewer_num), x, y);
+    }
+
+    game.tick();
+}
+
+#[when(regex = r"(\d+) seconds? (?:has|have) passed,")]
+fn when_seconds_pass(game: &mut MockGame, num_seconds: u64) {
+    game.pass_time(Duration::from_secs(num_seconds));
+}
+
+#[when(regex = r"(\d+) frames? (?:has|have) passed,")]
+fn when_frames_pass(game: &mut MockGame, frames_to_pass: usize) {
+    for _i in 0..frames_to_pass {
+        game.tick();
+    }
+}
+
+#[then(regex = r"the game should receive (\d+) clicks?.")]
+fn verify_num_clicks_received(game: &mut MockGame, expected_num_clicks: usize) {
+    let actual_num_clicks = game.get_messages::<ViewerClick>().len();
+    assert_eq!(expected_num_clicks, actual_num_clicks);
+}
+
+#[then(regex = r"the game should receive a click at ([0-9.]+), ([0-9.]+).")]
+fn verify_click_received(game: &mut MockGame, expected_x: f32, expected_y: f32) {
+    let click_received = game
+        .get_messages::<ViewerClick>()
+        .iter()
+        .any(|viewer_click| {
+            viewer_click.get_x() == expected_x && viewer_click.get_y() == expected_y
+        });
+    assert!(
+        click_received,
+        "No click was received at the given location."
+    );
+}
+
+#[then(regex = r"the game should receive a click from '(.+)'.")]
+fn verify_click_received_from(game: &mut MockGame, expected_viewer_id: String) {
+    let click_received = game
+        .get_messages::<ViewerClick>()
+        .iter()
+        .any(|viewer_click| *viewer_click.get_viewer().get_id() == expected_viewer_id);
+    assert!(
+        click_received,
+        "No click was received from the given viewer."
+    );
+}
+
+#[then(regex = r"(\d+) clicks? should have been dropped for coming too soon.")]
+fn verify_num_dropped_on_cooldown(game: &mut MockGame, expected_num_dropped: usize) {
+    let click_limiter = game.get_resource::<ClickLimiter>();
+    assert_eq!(
+        click_limiter.get_num_dropped_on_cooldown(),
+        expected_num_dropped
+    );
+}
+
+#[then(regex = r"(\d+) clicks? should have been dropped for going over the cap.")]
+fn verify_num_dropped_over_cap(game: &mut MockGame, expected_num_dropped: usize) {
+    let click_limiter = game.get_resource::<ClickLimiter>();
+    assert_eq!(
+        click_limiter.get_num_dropped_over_cap(),
+        expected_num_dropped
+    );
+}
+
+#[then(regex = r"'(.+)' should click on the tile at (\d+), (\d+).")]
+fn verify_tile_clicked(game: &mut MockGame, expected_viewer_id: String, x: usize, y: usize) {
+    let expected_coordinates = LogicalCoordinates::new(x, y, 0);
+    let tile_clicked = game.get_messages::<TileClicked>().iter().any(|tile_click| {
+        *tile_click.get_viewer().get_id() == expected_viewer_id
+            && *tile_click.get_coordinates() == expected_coordinates
+    });
+    assert!(
+        tile_clicked,
+        "The viewer did not click on the expected tile."
+    );
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/clicking.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
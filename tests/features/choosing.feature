@@ -0,0 +1,148 @@
+Feature: Viewers pick what campers do from a panel along the bottom of the stream.
+    Scenario: No scenario is shown while every camper is on the bus.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then the scenario panel should not be shown.
+
+    Scenario: A camper reaching the meadows is given a scenario for their objective.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        Then the scenario panel should be shown.
+        And the scenario panel should be titled 'What would you like to do?'
+        And the scenario panel should describe 'There seem to be fish swimming in there. What do you do?'
+        And the scenario panel should describe 'You stumble upon a river with water violently moving to the east.'
+        And the scenario panel should have 1 choices.
+        And the 1st choice box should read 'Try spearfishing with a stick nearby.'
+
+    Scenario: A scenario with many choices shows a box for each of them.
+        Given the objectives directory 'voting_objectives',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        Then the scenario panel should have 2 choices.
+        And the 1st choice box should read 'Try spearfishing with a stick nearby.'
+        And the 2nd choice box should read 'Reach in and try to grab one with your bare hands.'
+
+    Scenario: Clicking on a choice sends the camper back to the bus.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 2' clicks on the 1st choice,
+        Then the scenario panel should not be shown.
+        And the camper for 'Player 1' should be heading back to the bus.
+
+    Scenario: Clicking outside of the choices does not make a choice.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 2' clicks at 0.5, 0.3,
+        Then the scenario panel should be shown.
+        And the camper for 'Player 1' should still be in the meadows.
+
+    Scenario: Every choice shows how many viewers are voting for it.
+        Given the objectives directory 'voting_objectives',
+        And the voting window lasts 60 seconds,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 1' clicks on the 1st choice,
+        And 'Viewer 2' clicks on the 2nd choice,
+        And 'Viewer 3' clicks on the 2nd choice,
+        Then the 1st choice box should show '1 vote'.
+        And the 2nd choice box should show '2 votes'.
+        And the camper for 'Player 1' should still be in the meadows.
+
+    Scenario: Vote counts are shown in the stream's language.
+        Given the voting window lasts 60 seconds,
+        And the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 1' clicks on the 1st choice,
+        Then the 1st choice box should show '1 voto'.
+        When 'Viewer 2' clicks on the 1st choice,
+        Then the 1st choice box should show '2 votos'.
+
+    Scenario: The scenario panel is titled in the stream's language.
+        Given the locale 'de',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        Then the scenario panel should be titled 'Was möchtet ihr tun?'
+
+    Scenario: A viewer changing their mind only moves their one vote.
+        Given the objectives directory 'voting_objectives',
+        And the voting window lasts 60 seconds,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 1' clicks on the 1st choice,
+        And 'Viewer 1' clicks on the 1st choice,
+        And 'Viewer 1' clicks on the 2nd choice,
+        Then the 1st choice box should show '0 votes'.
+        And the 2nd choice box should show '1 vote'.
+
+    Scenario: The choice with the most votes is made once voting closes.
+        Given the objectives directory 'voting_objectives',
+        And the voting window lasts 60 seconds,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 1' clicks on the 1st choice,
+        And 'Viewer 2' clicks on the 2nd choice,
+        And 'Viewer 3' clicks on the 2nd choice,
+        And the voting window closes,
+        Then 'Player 1' should have gone with the 2nd choice with 2 votes.
+        And the scenario panel should not be shown.
+        And the camper for 'Player 1' should be heading back to the bus.
+
+    Scenario: The countdown bar shrinks as the time to vote runs out.
+        Given the voting window lasts 60 seconds,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 45 seconds of voting pass,
+        Then the countdown bar should be 25% full.
+
+    Scenario: Voting starts over when nobody votes in time.
+        Given the voting window lasts 60 seconds,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 60 seconds of voting pass,
+        Then the scenario panel should be shown.
+        And the countdown bar should be 100% full.
+        And the camper for 'Player 1' should still be in the meadows.
+
+    Scenario Outline: Ties are broken by the chosen rule.
+        Given the objectives directory 'voting_objectives',
+        And the voting window lasts 60 seconds,
+        And ties are broken by <rule>,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        And 'Viewer 1' clicks on the 2nd choice,
+        And 'Viewer 2' clicks on the 1st choice,
+        And the voting window closes,
+        Then 'Player 1' should have gone with the <winner> choice with 1 votes.
+
+        Examples:
+            | rule             | winner |
+            | the first listed | 1st    |
+            | the first voted  | 2nd    |
+
+    Scenario: Random tie breaks turn out the same for the same seed.
+        Then a tie between 3 choices broken at random with the seed 11 should go the same way every time.
+
+    Scenario Outline: Clicks on the stream land on the choice drawn under them.
+        Then a click at <x>, <y> with <choices> choices should land on <choice>.
+
+        Examples:
+            | x    | y    | choices | choice      |
+            | 0.25 | 0.1  | 2       | choice 1    |
+            | 0.75 | 0.1  | 2       | choice 2    |
+            | 0.5  | 0.15 | 3       | choice 2    |
+            | 1.0  | 0.0  | 3       | choice 3    |
+            | 0.5  | 0.2  | 2       | no choice   |
+            | 0.5  | 0.3  | 2       | no choice   |
+            | 0.5  | 0.9  | 2       | no choice   |
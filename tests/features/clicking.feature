@@ -0,0 +1,83 @@
+Feature: Clicks from viewers reach the game no matter where they come from.
+    Scenario: A click sent through a channel reaches the game.
+        Given a click channel,
+        When 'Viewer 1' clicks at 0.25, 0.75 through the click channel,
+        Then the game should receive 1 click.
+        And the game should receive a click at 0.25, 0.75.
+        And the game should receive a click from 'Viewer 1'.
+
+    Scenario: A scripted click has its y-axis flipped like clicks from Twitch.
+        Given a click script with a click after 0 seconds at 0.25, 0.75,
+        When 1 frame has passed,
+        Then the game should receive 1 click.
+        And the game should receive a click at 0.25, 0.25.
+
+    Scenario: A scripted click does not arrive before its time.
+        Given a click script with a click after 60 seconds at 0.5, 0.5,
+        When 1 frame has passed,
+        Then the game should receive 0 clicks.
+
+    Scenario: Clicks from every click source reach the game.
+        Given a click channel,
+        And a click script with a click after 0 seconds at 0.5, 0.5,
+        When 'Viewer 1' clicks at 0.1, 0.1 through the click channel,
+        Then the game should receive 2 clicks.
+
+    Scenario: A scripted click can say which viewer it comes from.
+        Given a click script with a click after 0 seconds at 0.5, 0.5 from 'U42',
+        When 1 frame has passed,
+        Then the game should receive a click from 'U42'.
+
+    Scenario: A click on the stream lands on the tile drawn under it.
+        # The 40 by 23 map of 16 pixel tiles is drawn twice as large
+        # to fill the 1280 by 720 window, centered on the map.
+        Given the campsite map 'campsite_1.tmx' has been rendered,
+        And a click channel,
+        When 'Viewer 1' clicks at 0.2625, 0.5 through the click channel,
+        Then 'Viewer 1' should click on the tile at 10, 11.
+
+    Scenario: Clicks near the top of the stream land on the top of the map.
+        Given the campsite map 'campsite_1.tmx' has been rendered,
+        And a click channel,
+        When 'Viewer 1' clicks at 0.0125, 0.99 through the click channel,
+        Then 'Viewer 1' should click on the tile at 0, 0.
+
+    Scenario: A viewer clicking again too soon only has their first click counted.
+        Given a click channel,
+        And viewers have to wait 1 second between clicks,
+        When 'Viewer 1' clicks at 0.5, 0.5 through the click channel 3 times,
+        Then the game should receive 1 click.
+        And 2 clicks should have been dropped for coming too soon.
+
+    Scenario: A viewer can click again once their cooldown is over.
+        Given a click channel,
+        And viewers have to wait 1 second between clicks,
+        When 'Viewer 1' clicks at 0.5, 0.5 through the click channel,
+        And 1 second has passed,
+        And 'Viewer 1' clicks at 0.5, 0.5 through the click channel,
+        Then the game should receive 1 click.
+        And 0 clicks should have been dropped for coming too soon.
+
+    Scenario: One viewer cooling down does not hold back anyone else.
+        Given a click channel,
+        And viewers have to wait 1 second between clicks,
+        When 'Viewer 1' clicks at 0.5, 0.5 through the click channel,
+        And 'Viewer 2' clicks at 0.5, 0.5 through the click channel,
+        Then the game should receive a click from 'Viewer 2'.
+        And 0 clicks should have been dropped for coming too soon.
+
+    Scenario: Clicks past the cap on clicks from everyone are dropped.
+        Given a click channel,
+        And the game accepts at most 2 clicks per second,
+        When 3 viewers click at 0.5, 0.5 through the click channel,
+        Then the game should receive 2 clicks.
+        And 1 click should have been dropped for going over the cap.
+
+    Scenario: The cap on clicks from everyone starts over every second.
+        Given a click channel,
+        And the game accepts at most 2 clicks per second,
+        When 3 viewers click at 0.5, 0.5 through the click channel,
+        And 1 second has passed,
+        And 2 viewers click at 0.5, 0.5 through the click channel,
+        Then the game should receive 2 clicks.
+        And 1 click should have been dropped for going over the cap.
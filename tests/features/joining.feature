@@ -0,0 +1,103 @@
+Feature: Viewers join the trip from a lobby before it begins.
+    Scenario: The game opens on a title screen nobody has joined yet.
+        Given the game opens on the lobby,
+        Then the lobby should be shown.
+        And the lobby roster should read 'Nobody has joined yet.'
+        And the campers should still be in the lobby.
+
+    Scenario: Viewers join the trip by clicking on the title screen.
+        Given the game opens on the lobby,
+        When 'Viewer 1' clicks on the title screen,
+        And 'Viewer 2' clicks on the title screen,
+        And 'Viewer 1' clicks on the title screen,
+        Then there should be 2 campers on the roster.
+        And 'Viewer 2' should be camper 2.
+        And the lobby roster should read 'Camper 1: Viewer 1'
+        And the lobby roster should read 'Camper 2: Viewer 2'
+
+    Scenario: The lobby keeps waiting until enough campers have joined.
+        Given the lobby stays open for 10 seconds and needs 2 campers,
+        And the game opens on the lobby,
+        When 'Viewer 1' clicks on the title screen,
+        And 10 seconds have passed,
+        Then the campers should still be in the lobby.
+        And the lobby status should read 'Waiting for 1 more camper to join...'
+
+    Scenario: The lobby is shown in the stream's language.
+        Given the lobby stays open for 10 seconds and needs 2 campers,
+        And the locale 'de',
+        And the game opens on the lobby,
+        Then the lobby title should read 'Überleben auf der Reise'
+        And the lobby roster should read 'Noch ist niemand dabei.'
+        When 'Viewer 1' clicks on the title screen,
+        Then the lobby status should read 'Warte auf 1 weiteren Camper...'
+        And the lobby roster should read 'Camper 1: Viewer 1'
+
+    Scenario: The lobby falls back to English for a language it has not been translated to.
+        Given the lobby stays open for 10 seconds and needs 3 campers,
+        And the locale 'fr',
+        And the game opens on the lobby,
+        When 'Viewer 1' clicks on the title screen,
+        Then the lobby status should read 'Waiting for 2 more campers to join...'
+
+    Scenario: The trip begins once the lobby's time is up with enough campers.
+        Given the lobby stays open for 10 seconds and needs 1 campers,
+        And the game opens on the lobby,
+        When 'Viewer 1' clicks on the title screen,
+        And 4 seconds have passed,
+        Then the lobby status should read 'The bus leaves in 6 seconds...'
+        When 6 seconds have passed,
+        And 1 frame has passed,
+        Then all campers should be alive.
+        And the lobby should not be shown.
+
+    Scenario: The streamer can begin the trip without waiting.
+        Given the game opens on the lobby,
+        When 'Viewer 1' clicks on the title screen,
+        And the streamer starts the trip,
+        Then all campers should be alive.
+        And the lobby should not be shown.
+
+    Scenario: The streamer cannot begin the trip before anyone has joined.
+        Given the game opens on the lobby,
+        When the streamer starts the trip,
+        Then the campers should still be in the lobby.
+        And the lobby should be shown.
+
+    Scenario: Nobody gets hungry while waiting in the lobby.
+        Given the game opens on the lobby,
+        When 5 frames have passed,
+        Then the hunger bar should be at 100%.
+
+    Scenario: The first campsite of the trip is only loaded once the lobby closes.
+        Given the game opens on the lobby,
+        And a trip called 'short_trip.json',
+        When the trip is waiting in the lobby,
+        Then there should be 0 objectives.
+        When 'Viewer 1' clicks on the title screen,
+        And the streamer starts the trip,
+        Then the current campsite should be 'campsite_1'.
+        And there should be 2 objectives.
+
+    Scenario: Only viewers who joined in the lobby have campers on the trip.
+        Given the game opens on the lobby,
+        And a trip called 'short_trip.json',
+        When the trip is waiting in the lobby,
+        And 'Viewer 1' clicks on the title screen,
+        And the streamer starts the trip,
+        And 'Viewer 2' attempts the 1st objective,
+        And 'Viewer 1' attempts the 2nd objective,
+        And 1 frame has passed,
+        Then 'Viewer 1' should have a camper on the trip.
+        And 'Viewer 2' should not have a camper on the trip.
+
+    Scenario: Anyone can have a camper when the trip begins with nobody on the roster.
+        Given the lobby stays open for 10 seconds and needs 0 campers,
+        And the game opens on the lobby,
+        And a trip called 'short_trip.json',
+        When the trip is waiting in the lobby,
+        And 10 seconds have passed,
+        And 3 frames have passed,
+        And 'Viewer 2' attempts the 1st objective,
+        And 1 frame has passed,
+        Then 'Viewer 2' should have a camper on the trip.
@@ -0,0 +1,58 @@
+Feature: Mistakes in campsites are caught before a stream.
+    Scenario: A campsite without mistakes has no problems.
+        Given the campsite map 'maps/campsite_1.tmx',
+        And the objectives file 'objectives/campsite_1_objectives.json',
+        When the campsite is linted,
+        Then there should be 0 problems.
+
+    Scenario: Every problem with an objectives file is reported at once.
+        Given the campsite map 'maps/campsite_1.tmx',
+        And the objectives file 'broken_objectives/many_problems.json',
+        When the campsite is linted,
+        Then there should be 4 problems.
+        And there should be a problem at 'landmarks[0].scenarios[0].objective'.
+        And there should be a problem at 'objectives[0].achieved_message' mentioning 'Unknown placeholder {campr}'.
+        And there should be a problem at 'objectives[0]' mentioning 'no scenarios'.
+        And there should be a problem at 'objectives[1]' mentioning 'no scenarios'.
+
+    Scenario: Problems with a map's locations of interest are reported.
+        Given the campsite map 'broken_maps/no_bus_exit.tmx',
+        And the objectives file 'objectives/campsite_1_objectives.json',
+        When the campsite is linted,
+        Then there should be 2 problems.
+        And there should be a problem at 'Locations of Interest' mentioning 'Unknown object found: Campfire'.
+        And there should be a problem at 'Locations of Interest' mentioning 'Could not find Bus Exit'.
+
+    Scenario: A map that cannot be loaded is reported.
+        Given the campsite map 'maps/campsite_404.tmx',
+        And the objectives file 'objectives/campsite_1_objectives.json',
+        When the campsite is linted,
+        Then there should be 1 problems.
+
+    Scenario: The linter fails when it finds problems.
+        Given the campsite map 'maps/campsite_1.tmx',
+        And the objectives directory 'broken_objectives',
+        When the linter is run,
+        Then the linter should fail.
+
+    Scenario: The linter succeeds when it finds no problems.
+        Given the campsite map 'maps/campsite_1.tmx',
+        And the objectives directory 'objectives',
+        When the linter is run,
+        Then the linter should succeed.
+
+    Scenario: The linter falls back to English objectives just like the game.
+        Given the campsite map 'maps/campsite_1.tmx',
+        And the objectives directory 'objectives',
+        And the locale 'de',
+        When the linter is run,
+        Then the linter should succeed.
+
+    Scenario Outline: A mistyped command shows how to use the linter.
+        When the linter is run with '<arguments>',
+        Then the linter should fail by showing how to use it.
+
+        Examples:
+            | arguments                                   |
+            | --objectives-dr tests/assets/objectives     |
+            | tests/assets/maps/campsite_1.tmx --locale   |
@@ -0,0 +1,30 @@
+Feature: Campsites are shown in the language of the stream.
+    Scenario: A translated campsite uses its translated objectives.
+        Given the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then the 1st objective should be called 'Buscar comida.'
+        And the 1st landmark should be called 'Río'
+
+    Scenario: A translated objective without its own wording reads as completed.
+        Given the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' succeeds the 1st objective,
+        Then '¡Player 1 completó «Buscar comida»!' should be in the contributions list.
+
+    Scenario: A translated objective uses its own wording.
+        Given the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' succeeds the 2nd objective,
+        Then '¡Player 1 juntó un montón de palos!' should be in the contributions list.
+
+    Scenario: A campsite that has not been translated falls back to English.
+        Given the locale 'de',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' succeeds the 2nd objective,
+        Then the 2nd objective should be called 'Find food.'
+        And 'Player 1 found food!' should be in the contributions list.
+
+    Scenario: The linter checks translated objectives.
+        Given the locale 'es',
+        When the campsite map 'campsite_1.tmx' is linted,
+        Then the linter should succeed.
@@ -0,0 +1,57 @@
+Feature: Viewer clicks keep coming in even when the connection to Twitch is unreliable.
+    Scenario: The wait between reconnection attempts doubles every time.
+        Given a reconnection backoff starting at 1 second and capped at 30 seconds,
+        Then the delay before reconnection attempt 1 should be 1 second.
+        And the delay before reconnection attempt 2 should be 2 seconds.
+        And the delay before reconnection attempt 3 should be 4 seconds.
+        And the delay before reconnection attempt 5 should be 16 seconds.
+
+    Scenario: The wait between reconnection attempts never goes past the cap.
+        Given a reconnection backoff starting at 1 second and capped at 30 seconds,
+        Then the delay before reconnection attempt 6 should be 30 seconds.
+        And the delay before reconnection attempt 100 should be 30 seconds.
+
+    Scenario: Jitter never shortens the wait by more than half.
+        Given a reconnection backoff starting at 4 seconds and capped at 30 seconds,
+        Then the jittered delay before reconnection attempt 1 should be between 2 and 4 seconds.
+
+    Scenario: Reconnecting stops once too many attempts have failed.
+        Given a reconnection backoff starting at 1 second and capped at 30 seconds,
+        And the reconnection backoff gives up after 3 attempts,
+        Then reconnection attempt 3 should still be made.
+        And reconnection attempt 4 should not be made.
+
+    Scenario: Clicks from a Heat-compatible server reach the click listener.
+        Given a mock Heat server with a click after 0 seconds at 0.25, 0.75 from 'U1234',
+        When the click listener connects to the mock Heat server,
+        Then the click listener should be connected.
+        And the click listener should receive a click at 0.25, 0.25 from 'U1234'.
+
+    Scenario: The click listener reports failing once it gives up.
+        Given a reconnection backoff starting at 0 seconds and capped at 0 seconds,
+        And the reconnection backoff gives up after 1 attempts,
+        When the click listener connects to an address nobody is listening on,
+        Then the click listener should have failed to connect.
+
+    Scenario: Clicks the game cannot keep up with are dropped.
+        Given a mock Heat server with 10 clicks after 0 seconds at 0.5, 0.5 from 'U1234',
+        When the click listener connects to the mock Heat server holding at most 4 clicks,
+        Then the click listener should drop 6 clicks.
+        And the click listener should have 4 clicks waiting.
+
+    Scenario: A click frame from Heat is read as a click from that viewer.
+        When the Heat frame '{"type": "click", "id": "U12345678", "x": "0.25", "y": "0.75", "modifiers": {"alt": false, "ctrl": false, "shift": false}}' is read,
+        Then the frame should be read as a click at 0.25, 0.25 from 'U12345678'.
+
+    Scenario: A click frame with the viewer's names uses their display name.
+        When the Heat frame '{"type": "click", "id": "U12345678", "login": "trailblazer", "display_name": "TrailBlazer", "x": "0.5", "y": "0.5"}' is read,
+        Then the frame should be read as a click at 0.5, 0.5 from 'U12345678'.
+        And the clicking viewer should be called 'TrailBlazer'.
+
+    Scenario: A click frame without an id is read as a click from an anonymous viewer.
+        When the Heat frame '{"type": "click", "x": "0.5", "y": "0.5"}' is read,
+        Then the frame should be read as a click at 0.5, 0.5 from 'Anonymous'.
+
+    Scenario: Frames that are not clicks are not read as clicks.
+        When the Heat frame '{"type": "system", "message": "Connected to Heat API server."}' is read,
+        Then the frame should not be read as a click.
@@ -0,0 +1,53 @@
+Feature: Mistakes in objectives files are pointed out instead of crashing the game.
+    Scenario: A well-formed objectives file loads.
+        Given the objectives file 'objectives/campsite_1_objectives.json',
+        When the objectives file is loaded,
+        Then the objectives file should load.
+
+    Scenario: A missing field is pointed out.
+        Given the objectives file 'broken_objectives/missing_choices.json',
+        When the objectives file is loaded,
+        Then the objectives file should fail at 'landmarks[0].scenarios[0]'.
+        And the problem should mention 'missing field `choices`'.
+
+    Scenario: A misspelled field is pointed out.
+        Given the objectives file 'broken_objectives/campsite_1_objectives.json',
+        When the objectives file is loaded,
+        Then the objectives file should fail at 'landmarks[0].scenarios[0].choices[0].hunger_restord'.
+        And the problem should mention 'unknown field `hunger_restord`'.
+
+    Scenario: A scenario for an objective that does not exist is pointed out.
+        Given the objectives file 'broken_objectives/unknown_objective.json',
+        When the objectives file is loaded,
+        Then the objectives file should fail at 'landmarks[0].scenarios[0].objective'.
+        And the problem should mention 'Find treasure.'.
+
+    Scenario: An objectives file from a newer version of the game is pointed out.
+        Given the objectives file 'broken_objectives/future_version.json',
+        When the objectives file is loaded,
+        Then the objectives file should fail at 'schema_version'.
+        And the problem should mention 'Unsupported schema version 2'.
+
+    Scenario: The game shows what is wrong with a campsite's objectives file.
+        Given the objectives directory 'broken_objectives',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then the objectives error should be shown.
+        And there should be 0 objectives.
+
+    Scenario: What is wrong with a campsite's objectives file is shown in the stream's language.
+        Given the objectives directory 'broken_objectives',
+        And the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then the objectives error should read 'No se pudo cargar'
+
+    Scenario: A campsite whose objectives file cannot be loaded is skipped.
+        Given the objectives directory 'broken_objectives',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then there should be an empty contributions list.
+        And the bus should be heading to the exit.
+
+    Scenario: A success chance over 100% is pointed out.
+        Given the objectives file 'broken_objectives/impossible_chance.json',
+        When the objectives file is loaded,
+        Then the objectives file should fail at 'landmarks[0].scenarios[0].choices[0].success_chance'.
+        And the problem should mention 'more than 100%'.
@@ -0,0 +1,32 @@
+Feature: Campers keep out of each other's way as they travel.
+    Scenario: Campers step off of the bus one at a time.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 3 viewers attempt an objective at the same time,
+        Then there should be 1 camper outside of the bus.
+        And 2 campers should be waiting in the bus.
+
+    Scenario: The next camper steps off of the bus once the door is clear.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 3 viewers attempt an objective at the same time,
+        And a step passes,
+        Then there should be 2 campers outside of the bus.
+        And 1 camper should be waiting in the bus.
+
+    Scenario: Campers never stand on the same spot as each other.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 5 viewers attempt an objective at the same time,
+        Then every camper should reach the meadows without sharing a spot with another camper.
+
+    Scenario: Campers never run into each other over a narrow crossing.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        And 'Camper A' reserves the cheapest path from 4, 2 to 8, 2,
+        Then the path reserved for 'Camper B' from 6, 1 to 4, 1 should never run into 'Camper A'.
+
+    Scenario: Campers wait for the way to clear instead of walking through each other.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        And 'Camper A' reserves the cheapest path from 4, 2 to 8, 2,
+        Then the path reserved for 'Camper B' from 6, 1 to 4, 1 should take 6 steps.
+
+    Scenario: Campers with nobody in their way take the cheapest path.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the path reserved for 'Camper B' from 6, 1 to 4, 1 should take 4 steps.
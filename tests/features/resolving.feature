@@ -0,0 +1,49 @@
+Feature: Choices succeed or fail by chance, shaped by how the campers are doing.
+    Scenario: A choice starts from its own chance of success.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 50% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 1 votes should be 70%.
+
+    Scenario: A choice without a chance of success is a coin flip.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 50% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 2nd scenario in the 1st landmark with 1 votes should be 50%.
+
+    Scenario: Hungry campers are less likely to succeed.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 0% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 1 votes should be 60%.
+
+    Scenario: Well-fed campers are more likely to succeed.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 100% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 1 votes should be 80%.
+
+    Scenario: Choices with more votes are more likely to succeed, up to a point.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 50% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 3 votes should be 80%.
+        And the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 10 votes should be 90%.
+
+    Scenario: Campers who have done well before are more likely to succeed.
+        Given 'Player 1' has succeeded 2 times and failed 0 times,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 50% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 1 votes should be 80%.
+
+    Scenario: Campers who have done poorly before are less likely to succeed, up to a point.
+        Given 'Player 1' has succeeded 0 times and failed 5 times,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the hunger bar is set to 50% full,
+        Then the chance of 'Player 1' succeeding with the 1st choice from the 1st scenario in the 1st landmark with 1 votes should be 55%.
+
+    Scenario: Making a choice lets everyone know how it turned out.
+        Given the outcome seed 7,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' makes the 1st choice from the 1st scenario in the 1st landmark with 1 votes,
+        Then 'Player 1' should have attempted 'Find food.' with the 1st choice from the 1st scenario in the 1st landmark.
+        And 'Player 1' should have 1 past results.
+
+    Scenario: The same seed always leads to the same outcomes.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then 'Player 1' making the 1st choice from the 1st scenario in the 1st landmark 20 times with the seed 7 should turn out the same every time.
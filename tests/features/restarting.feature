@@ -0,0 +1,49 @@
+Feature: The game starts over on its own after the campers starve.
+    Scenario: A countdown to the restart is shown once the campers starve.
+        Given the game restarts 10 seconds after the campers starve,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the campers starve,
+        Then all campers should be dead.
+        And the countdown should read 'Starting over in 10 seconds...'
+
+    Scenario: The countdown is shown in the stream's language.
+        Given the game restarts 10 seconds after the campers starve,
+        And the locale 'es',
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the campers starve,
+        Then the countdown should read 'Empezando de nuevo en 10 segundos...'
+
+    Scenario: The countdown goes down as time passes.
+        Given the game restarts 10 seconds after the campers starve,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And the campers starve,
+        And 4 seconds have passed,
+        Then all campers should be dead.
+        And the countdown should read 'Starting over in 6 seconds...'
+
+    Scenario: The campers come back to life at the first campsite once the countdown runs out.
+        Given the game restarts 10 seconds after the campers starve,
+        And the hunger bar only goes down when campers fail,
+        When the campsite map 'campsite_1.tmx' is rendered,
+        And 'Player 1' succeeds the 2nd objective,
+        And the campers starve,
+        And the countdown runs out,
+        Then all campers should be alive.
+        And the hunger bar should be at 100%.
+        And there should be no campers.
+        And there should be 2 objectives.
+        And there should be 0 contributions.
+        And the countdown should not be shown.
+
+    Scenario: Starving during a trip starts the trip over.
+        Given the game restarts 10 seconds after the campers starve,
+        And a trip called 'short_trip.json',
+        When the trip begins,
+        And 'Player 1' succeeds the 2nd objective,
+        And the campsite is cleared,
+        And the campers starve,
+        And the countdown runs out,
+        Then the current campsite should be 'campsite_1'.
+        And 0 campsites should have been survived.
+        And there should be 0 total contributions.
+        And there should be 2 objectives.
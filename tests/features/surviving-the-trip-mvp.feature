@@ -11,6 +11,17 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         # map_depth = number of layers = 3 (Overworld, Trees and Details, Bus)
         Then the size of the map should be 40 by 23 by 4.
 
+    Scenario: A map with mistakes in its locations of interest still loads.
+        Given a campsite map called '../broken_maps/no_bus_exit.tmx',
+        When the campsite map is rendered,
+        Then the size of the map should be 40 by 23 by 4.
+        And there should be 2 locations of interest.
+
+    Scenario: Every spot on a map wider than it is tall has its own number.
+        Given a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        Then every spot in the map should have its own number.
+
     Scenario: The Hunger bar ticks down as time passes.
         Given a hunger bar set to 100% full,
         And the hunger bar decreases by 10% every second,
@@ -25,6 +36,30 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         Then the hunger bar should be at 0%.
         And all campers should be dead.
 
+    Scenario: Succeeding at a choice fills the hunger bar back up.
+        Given a hunger bar set to 50% full,
+        And the hunger bar decreases by 0% every second,
+        And a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' succeeds with the 1st choice from the 1st scenario in the 1st landmark,
+        Then the hunger bar should be at 70%.
+
+    Scenario: The hunger bar is never filled past 100%.
+        Given a hunger bar set to 90% full,
+        And the hunger bar decreases by 0% every second,
+        And a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' succeeds with the 1st choice from the 1st scenario in the 1st landmark,
+        Then the hunger bar should be at 100%.
+
+    Scenario: Failing at a choice empties the hunger bar some.
+        Given a hunger bar set to 50% full,
+        And the hunger bar decreases by 0% every second,
+        And a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' fails with the 1st choice from the 1st scenario in the 1st landmark,
+        Then the hunger bar should be at 45%.
+
     Scenario: A list of objectives should show up depending on the loaded map.
         Given a campsite map called 'campsite_1.tmx',
         When the campsite map is rendered,
@@ -58,6 +93,31 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         And 'Player 1' succeeds the 2nd scenario's objective,
         Then 'Player 1 found food!' should be in the contributions list.
 
+    Scenario: An objective's own wording is used on the contributions list.
+        Given a campsite map called 'campsite_2.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' succeeds the 1st scenario's objective,
+        Then 'Player 1 filled the water bottles!' should be in the contributions list.
+
+    Scenario Outline: Any objective reads naturally on the contributions list.
+        Given a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' succeeds at '<objective>',
+        Then '<contribution>' should be in the contributions list.
+
+        Examples:
+            | objective                 | contribution                          |
+            | Seek sticks.              | Player 1 found sticks!                |
+            | Find dry firewood.        | Player 1 found dry firewood!          |
+            | Build a shelter.          | Player 1 built a shelter!             |
+            | Catch some fish.          | Player 1 caught some fish!            |
+            | Gather berries.           | Player 1 gathered berries!            |
+            | Bake bread.               | Player 1 baked bread!                 |
+            | Carry the water.          | Player 1 carried the water!           |
+            | Trap a rabbit.            | Player 1 trapped a rabbit!            |
+            | Visit the old cabin.      | Player 1 visited the old cabin!       |
+            | Find Grandma's lost hat.  | Player 1 found Grandma's lost hat!    |
+
     Scenario: A camper heads into the meadows when a player attempts an objective.
         Given a campsite map called 'campsite_1.tmx',
         When the campsite map is rendered,
@@ -72,6 +132,16 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         And 'Player 1' finishes traveling,
         Then there should be 0 campers present.
 
+    Scenario: A camper is no longer seen once they finish traveling.
+        Given a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And 'Player 1' attempts the 1st objective,
+        And 'Player 2' attempts the 2nd objective,
+        And 'Player 1' finishes traveling,
+        Then there should be 1 camper present.
+        When 'Player 2' finishes traveling,
+        Then there should be 0 campers present.
+
     Scenario: A camper heads back from the meadows when completing an objective.
         Given a campsite map called 'campsite_1.tmx',
         When the campsite map is rendered,
@@ -94,8 +164,19 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         And all objectives are completed,
         Then the camper for 'Player 1' should appear outside of the meadows.
         And the camper for 'Player 1' should be heading back to the bus.
-        And the camper for "Player 2" should appear outside of the meadows.
-        And the camper for "Player 2" should be heading back to the bus.
+        And the camper for 'Player 2' should appear outside of the meadows.
+        And the camper for 'Player 2' should be heading back to the bus.
+
+    Scenario: A bus drawn with many tiles is a single bus.
+        Given a campsite map called 'parked_bus.tmx',
+        When the campsite map is rendered,
+        Then there should be 1 bus made of 3 tiles.
+
+    Scenario: The whole bus is unloaded along with its campsite.
+        Given a campsite map called 'parked_bus.tmx',
+        When the campsite map is rendered,
+        And the campsite map 'campsite_1.tmx' is rendered,
+        Then there should be 1 bus made of 1 tile.
 
     Scenario: The bus leaves when all campers are finished with their objectives and back in the bus.
         Given a campsite map called 'campsite_1.tmx',
@@ -103,3 +184,11 @@ Feature: All of the basic features are implemented for Surviving the Trip.
         And all objectives are completed,
         And all campers are on the bus,
         Then the bus should be heading to the exit.
+
+    Scenario: The campsite is cleared once the bus reaches the exit.
+        Given a campsite map called 'campsite_1.tmx',
+        When the campsite map is rendered,
+        And all objectives are completed,
+        And all campers are on the bus,
+        And the bus reaches the exit,
+        Then the campsite should be cleared.
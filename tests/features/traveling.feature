@@ -0,0 +1,95 @@
+Feature: Maps decide where campers can walk, and how hard it is to get there.
+    Scenario: Every tile can be walked on in a map that does not say otherwise.
+        When the campsite map 'campsite_1.tmx' is rendered,
+        Then every spot in the map should be walkable.
+
+    Scenario Outline: Tiles that are marked as unwalkable cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the spot at <x>, <y> should not be walkable.
+
+        Examples:
+            | x | y |
+            | 5 | 0 |
+            | 5 | 5 |
+
+    Scenario: Every tile from a tileset marked as unwalkable cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the spot at 9, 4 should not be walkable.
+
+    Scenario: Tiles on a collision layer cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the spot at 3, 3 should not be walkable.
+
+    Scenario: A tile drawn over an unwalkable tile can make it walkable again.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the spot at 5, 2 should be walkable.
+        And stepping onto the spot at 5, 2 should cost 2.
+
+    Scenario Outline: Stepping onto a tile costs what the map says it does.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then stepping onto the spot at <x>, <y> should cost <cost>.
+
+        Examples:
+            | x | y | cost |
+            | 0 | 0 | 1    |
+            | 7 | 4 | 4    |
+
+    Scenario: Campers walk around tiles they cannot walk on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the path from outside of the bus to the meadows should go through the spot at 5, 2.
+        And the path from outside of the bus to the meadows should only go through walkable spots.
+
+    Scenario: The cheapest path goes around tiles that are costly to step onto.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the cheapest path from 6, 4 to 8, 4 should not go through the spot at 7, 4.
+
+    Scenario: The shortest path goes through tiles no matter how costly they are.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the shortest path from 6, 4 to 8, 4 should go through the spot at 7, 4.
+
+    Scenario: Moving diagonally cuts across open ground.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the cheapest diagonal path from 6, 0 to 8, 2 should go through 3 spots.
+        And the cheapest path from 6, 0 to 8, 2 should go through 5 spots.
+
+    Scenario: Moving diagonally never cuts across the corner of a tile that cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then the cheapest diagonal path from 4, 1 to 6, 3 should go through 5 spots.
+        And the cheapest diagonal path from 4, 1 to 6, 3 should go through the spot at 5, 2.
+
+    Scenario Outline: There is no path to a spot that cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then there should be no <kind> path from 0, 0 to 5, 0.
+
+        Examples:
+            | kind     |
+            | shortest |
+            | cheapest |
+            | diagonal |
+
+    Scenario: Exploring visits every spot that can be walked on, one step at a time.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then exploring from 0, 0 should visit every walkable spot.
+        And exploring from 0, 0 should only ever step to a spot next to the last one.
+
+    Scenario: Exploring does not walk back over every spot it has been to.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then exploring from 0, 0 should take fewer than 100 steps.
+
+    Scenario: Exploring a large open world does not run out of room.
+        Then exploring an open world of 300 by 300 tiles should visit every spot.
+
+    Scenario: A patrol stops once it has taken as many steps as it is allowed.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then a patrol of 10 steps from 0, 0 should take 10 steps.
+        And a patrol of 10 steps from 0, 0 should visit 11 different spots.
+
+    Scenario: A patrol ends early once there is nowhere new to go.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then a patrol of 1000 steps from 0, 0 should visit every walkable spot.
+        And a patrol of 1000 steps from 0, 0 should take fewer than 1000 steps.
+
+    Scenario: There is no route from a spot that cannot be walked on.
+        When the campsite map 'river_crossing.tmx' is rendered,
+        Then there should be no route exploring from 5, 0.
+        And there should be no route a patrol of 10 steps from 5, 0.
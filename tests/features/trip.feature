@@ -0,0 +1,49 @@
+Feature: Campers survive a trip by clearing one campsite after another.
+    Scenario: A trip begins at its first campsite.
+        Given a trip called 'short_trip.json',
+        When the trip begins,
+        Then the current campsite should be 'campsite_1'.
+        And there should be 2 objectives.
+        And 0 campsites should have been survived.
+
+    Scenario: Clearing a campsite moves the campers on to the next one.
+        Given a trip called 'short_trip.json',
+        When the trip begins,
+        And 'Player 1' succeeds the 2nd objective,
+        And the campsite is cleared,
+        Then the current campsite should be 'campsite_2'.
+        And there should be 1 objectives.
+        And 1 campsites should have been survived.
+        And there should be 1 total contributions.
+
+    Scenario: Contributions add up over the whole trip.
+        Given a trip called 'short_trip.json',
+        When the trip begins,
+        And 'Player 1' succeeds the 1st objective,
+        And 'Player 2' succeeds the 2nd objective,
+        And the campsite is cleared,
+        And 'Player 1' succeeds the 1st objective,
+        And the campsite is cleared,
+        Then there should be 3 total contributions.
+
+    Scenario: Clearing the last campsite finishes the trip.
+        Given a trip called 'short_trip.json',
+        When the trip begins,
+        And the campsite is cleared,
+        And the campsite is cleared,
+        Then 2 campsites should have been survived.
+        And the trip should be finished.
+
+    Scenario: A campsite can name its own objectives file.
+        Given a trip called 'own_objectives_trip.json',
+        When the trip begins,
+        Then the current campsite should be 'campsite_2'.
+        And there should be 2 objectives.
+
+    Scenario: A trip leading to a campsite outside of it cannot be loaded.
+        Given a trip called 'unknown_next_trip.json',
+        Then the trip should fail to load at 'campsites[0].next[0]'.
+
+    Scenario: A trip file that does not exist cannot be loaded.
+        Given a trip called 'trip_404.json',
+        Then the trip should fail to load at '.'.
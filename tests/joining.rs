@@ -0,0 +1,195 @@
+use std::{path::PathBuf, time::Duration};
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    CampersState,
+    interacting::{CamperInformation, ObjectiveAttempt, ViewerClick, ViewerIdentity},
+    joining::{
+        CamperRoster, LobbyRoster, LobbyRules, LobbyScreen, LobbyStatus, LobbyTitle, StartTrip,
+    },
+    phrasing::Locale,
+    progressing::{CamperObjective, HungerBar, TripProgress},
+    reserving::BusDoorQueue,
+};
+
+use bevy::prelude::{NextState, Text};
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(game: &mut MockGame, locale_code: String) {
+    *game.get_resource_mut::<Locale>() = Locale::new(locale_code);
+}
+
+#[given("the game opens on the lobby,")]
+fn given_game_opens_on_lobby(game: &mut MockGame) {
+    game.get_resource_mut::<NextState<CampersState>>()
+        .set(CampersState::Start);
+    game.tick();
+}
+
+#[given(regex = r"the lobby stays open for (\d+) seconds and needs (\d+) campers,")]
+fn given_lobby_rules(game: &mut MockGame, wait_secs: u64, min_campers: usize) {
+    *game.get_resource_mut::<LobbyRules>() =
+        LobbyRules::new(Duration::from_secs(wait_secs), min_campers);
+}
+
+#[given(regex = r"a trip called '(.+)',")]
+fn given_trip_file(game: &mut MockGame, trip_name: String) {
+    game.trip_name = trip_name;
+}
+
+#[when("the trip is waiting in the lobby,")]
+fn when_trip_waiting_in_lobby(game: &mut MockGame) {
+    let trip_name = game.trip_name.clone();
+    game.begin_trip(&trip_name);
+}
+
+#[when(regex = r"'(.+)' clicks on the title screen,")]
+fn when_viewer_clicks_title_screen(game: &mut MockGame, viewer_name: String) {
+    let viewer = ViewerIdentity::new(viewer_name);
+    game.broadcast(ViewerClick::new(viewer, 0.5, 0.5));
+}
+
+#[when(regex = r"(\d+) seconds have passed,")]
+fn when_seconds_pass(game: &mut MockGame, num_seconds: u64) {
+    game.pass_time(Duration::from_secs(num_seconds));
+}
+
+#[when(regex = r"(\d+) frames? (?:has|have) passed,")]
+fn when_frames_pass(game: &mut MockGame, frames_to_pass: usize) {
+    for _i in 0..frames_to_pass {
+        game.tick();
+    }
+}
+
+#[when("the streamer starts the trip,")]
+fn when_streamer_starts_trip(game: &mut MockGame) {
+    game.broadcast(StartTrip);
+    // One tick to leave the lobby,
+    game.tick();
+    // Another to unload whatever was there before,
+    game.tick();
+    // And another to load the first campsite.
+    game.tick();
+}
+
+#[when(regex = r"'(.+)' attempts the (\d+)[a-z]+ objective,")]
+fn when_viewer_attempts_objective(game: &mut MockGame, viewer_name: String, objective_num: usize) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    let objective_name = camper_objective.get_name();
+
+    let viewer = ViewerIdentity::new(viewer_name);
+    game.broadcast(ObjectiveAttempt::new(viewer, objective_name));
+}
+
+/// Returns whether a viewer's camper is out on the campsite or waiting in
+/// the bus to step out.
+fn has_camper_on_trip(game: &mut MockGame, viewer: &ViewerIdentity) -> bool {
+    let camper_out = game
+        .get_all::<CamperInformation>()
+        .iter()
+        .any(|camper_info| camper_info.get_viewer() == viewer);
+
+    camper_out || game.get_resource::<BusDoorQueue>().contains(viewer)
+}
+
+#[then("the lobby should be shown.")]
+fn verify_lobby_shown(game: &mut MockGame) {
+    assert_eq!(game.get_all::<LobbyScreen>().len(), 1);
+}
+
+#[then("the lobby should not be shown.")]
+fn verify_lobby_not_shown(game: &mut MockGame) {
+    assert!(game.get_all::<LobbyScreen>().is_empty());
+}
+
+#[then(regex = r"the lobby title should read '(.+)'")]
+fn verify_lobby_title_text(game: &mut MockGame, expected_text: String) {
+    let (title_text, _marker) = game.get_all_containing::<Text, LobbyTitle>()[0];
+    assert_eq!(title_text.0, expected_text);
+}
+
+#[then(regex = r"the lobby roster should read '(.+)'")]
+fn verify_lobby_roster_text(game: &mut MockGame, expected_text: String) {
+    let (roster_text, _marker) = game.get_all_containing::<Text, LobbyRoster>()[0];
+    assert!(roster_text.0.contains(&expected_text), "{}", roster_text.0);
+}
+
+#[then(regex = r"the lobby status should read '(.+)'")]
+fn verify_lobby_status_text(game: &mut MockGame, expected_text: String) {
+    let (status_text, _marker) = game.get_all_containing::<Text, LobbyStatus>()[0];
+    assert_eq!(status_text.0, expected_text);
+}
+
+#[then(regex = r"there should be (\d+) campers on the roster.")]
+fn verify_num_campers_on_roster(game: &mut MockGame, expected_num_campers: usize) {
+    assert_eq!(
+        game.get_resource::<CamperRoster>().len(),
+        expected_num_campers
+    );
+}
+
+#[then(regex = r"'(.+)' should be camper (\d+).")]
+fn verify_camper_num(game: &mut MockGame, viewer_name: String, expected_camper_num: usize) {
+    let viewer = ViewerIdentity::new(viewer_name);
+    let camper_num = game.get_resource::<CamperRoster>().get_camper_num(&viewer);
+    assert_eq!(camper_num, Some(expected_camper_num));
+}
+
+#[then(regex = r"'(.+)' should have a camper on the trip.")]
+fn verify_camper_on_trip(game: &mut MockGame, viewer_name: String) {
+    let viewer = ViewerIdentity::new(viewer_name);
+    assert!(has_camper_on_trip(game, &viewer));
+}
+
+#[then(regex = r"'(.+)' should not have a camper on the trip.")]
+fn verify_no_camper_on_trip(game: &mut MockGame, viewer_name: String) {
+    let viewer = ViewerIdentity::new(viewer_name);
+    assert!(!has_camper_on_trip(game, &viewer));
+}
+
+#[then("the campers should still be in the lobby.")]
+fn verify_still_in_lobby(game: &mut MockGame) {
+    let campers_state = *game.get_game_state::<CampersState>().get();
+    assert_eq!(campers_state, CampersState::Start);
+}
+
+#[then("all campers should be alive.")]
+fn verify_campers_alive(game: &mut MockGame) {
+    let campers_state = *game.get_game_state::<CampersState>().get();
+    assert_eq!(campers_state, CampersState::Alive);
+}
+
+#[then(regex = r"the hunger bar should be at (\d+)%.")]
+fn verify_hunger_bar_percentage(game: &mut MockGame, expected_percentage: usize) {
+    let hunger_bar = game.get_resource::<HungerBar>();
+    assert_eq!(hunger_bar.get_current_percentage(), expected_percentage);
+}
+
+#[then(regex = r"there should be (\d+) objectives.")]
+fn verify_num_objectives(game: &mut MockGame, expected_num_objectives: usize) {
+    assert_eq!(
+        game.get_all::<CamperObjective>().len(),
+        expected_num_objectives
+    );
+}
+
+#[then(regex = r"the current campsite should be '(.+)'.")]
+fn verify_current_campsite(game: &mut MockGame, expected_campsite_name: String) {
+    let current_campsite_name = game
+        .get_resource::<TripProgress>()
+        .get_current_campsite()
+        .expect("verify_current_campsite: The campers are not at any campsite.")
+        .get_name();
+    assert_eq!(current_campsite_name, expected_campsite_name);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/joining.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
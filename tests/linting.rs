@@ -0,0 +1,149 @@
+use std::{
+    path::PathBuf,
+    process::{Command, ExitStatus},
+};
+
+use cucumber::{World, given, then, when};
+use surviving_the_trip::core_logic::linting::{LintProblem, lint_campsite};
+
+#[derive(World, Debug, Default)]
+pub struct LintTesting {
+    map_path: PathBuf,
+    objectives_path: PathBuf,
+    objectives_directory: PathBuf,
+    locale_code: Option<String>,
+
+    lint_problems: Vec<LintProblem>,
+    linter_status: Option<ExitStatus>,
+    linter_errors: String,
+}
+
+/// Returns the path to a file or folder in the tests' assets folder.
+fn get_test_asset_path(asset_name: &str) -> PathBuf {
+    let mut test_asset_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    test_asset_path.push("tests/assets/");
+    test_asset_path.push(asset_name);
+
+    test_asset_path
+}
+
+#[given(regex = r"the campsite map '(.+)',")]
+fn given_campsite_map(linting: &mut LintTesting, map_name: String) {
+    linting.map_path = get_test_asset_path(&map_name);
+}
+
+#[given(regex = r"the objectives file '(.+)',")]
+fn given_objectives_file(linting: &mut LintTesting, objectives_file_name: String) {
+    linting.objectives_path = get_test_asset_path(&objectives_file_name);
+}
+
+#[given(regex = r"the objectives directory '(.+)',")]
+fn given_objectives_directory(linting: &mut LintTesting, objectives_directory_name: String) {
+    linting.objectives_directory = get_test_asset_path(&objectives_directory_name);
+}
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(linting: &mut LintTesting, locale_code: String) {
+    linting.locale_code = Some(locale_code);
+}
+
+#[when("the campsite is linted,")]
+fn when_campsite_linted(linting: &mut LintTesting) {
+    linting.lint_problems = lint_campsite(&linting.map_path, &linting.objectives_path);
+}
+
+#[when("the linter is run,")]
+fn when_linter_run(linting: &mut LintTesting) {
+    let mut linter = Command::new(env!("CARGO_BIN_EXE_campsite-lint"));
+    linter
+        .arg("--objectives-dir")
+        .arg(&linting.objectives_directory);
+    if let Some(locale_code) = &linting.locale_code {
+        linter.arg("--locale").arg(locale_code);
+    }
+
+    let linter_status = linter
+        .arg(&linting.map_path)
+        .output()
+        .expect("when_linter_run: Could not run the linter.")
+        .status;
+    linting.linter_status = Some(linter_status);
+}
+
+#[when(regex = r"the linter is run with '(.+)',")]
+fn when_linter_run_with(linting: &mut LintTesting, linter_arguments: String) {
+    let linter_output = Command::new(env!("CARGO_BIN_EXE_campsite-lint"))
+        .args(linter_arguments.split_whitespace())
+        .output()
+        .expect("when_linter_run_with: Could not run the linter.");
+    linting.linter_status = Some(linter_output.status);
+    linting.linter_errors = String::from_utf8_lossy(&linter_output.stderr).to_string();
+}
+
+#[then(regex = r"there should be (\d+) problems.")]
+fn verify_num_problems(linting: &mut LintTesting, expected_num_problems: usize) {
+    assert_eq!(
+        linting.lint_problems.len(),
+        expected_num_problems,
+        "{:#?}",
+        linting.lint_problems
+    );
+}
+
+#[then(regex = r"^there should be a problem at '([^']+)'.$")]
+fn verify_problem_at(linting: &mut LintTesting, expected_location: String) {
+    let problem_found = linting
+        .lint_problems
+        .iter()
+        .any(|lint_problem| *lint_problem.get_location() == expected_location);
+    assert!(problem_found, "{:#?}", linting.lint_problems);
+}
+
+#[then(regex = r"there should be a problem at '(.+)' mentioning '(.+)'.")]
+fn verify_problem_at_mentioning(
+    linting: &mut LintTesting,
+    expected_location: String,
+    expected_problem: String,
+) {
+    let problem_found = linting.lint_problems.iter().any(|lint_problem| {
+        *lint_problem.get_location() == expected_location
+            && lint_problem.get_problem().contains(&expected_problem)
+    });
+    assert!(problem_found, "{:#?}", linting.lint_problems);
+}
+
+#[then("the linter should fail.")]
+fn verify_linter_failed(linting: &mut LintTesting) {
+    let linter_status = linting
+        .linter_status
+        .expect("verify_linter_failed: The linter was never run.");
+    assert!(!linter_status.success());
+}
+
+#[then("the linter should fail by showing how to use it.")]
+fn verify_linter_showed_usage(linting: &mut LintTesting) {
+    let linter_status = linting
+        .linter_status
+        .expect("verify_linter_showed_usage: The linter was never run.");
+    assert_eq!(linter_status.code(), Some(1), "{}", linting.linter_errors);
+    assert!(
+        linting.linter_errors.starts_with("Usage:"),
+        "{}",
+        linting.linter_errors
+    );
+}
+
+#[then("the linter should succeed.")]
+fn verify_linter_succeeded(linting: &mut LintTesting) {
+    let linter_status = linting
+        .linter_status
+        .expect("verify_linter_succeeded: The linter was never run.");
+    assert!(linter_status.success());
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/linting.feature");
+
+    futures::executor::block_on(LintTesting::run(feature_path));
+}
@@ -0,0 +1,90 @@
+use std::{path::PathBuf, process::Command};
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    interacting::{ScenarioAttempt, ScenarioResult, ViewerIdentity},
+    phrasing::Locale,
+    progressing::{CamperObjective, ContributionsList, Landmark},
+};
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(game: &mut MockGame, locale_code: String) {
+    *game.get_resource_mut::<Locale>() = Locale::new(locale_code);
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[when(regex = r"the campsite map '(.+)' is linted,")]
+fn when_campsite_map_linted(game: &mut MockGame, map_name: String) {
+    let mut map_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    map_path.push("tests/assets/maps/");
+    map_path.push(map_name);
+    game.tiled_map_path = map_path;
+}
+
+#[when(regex = r"'(.+)' succeeds the (\d+)[a-z]+ objective,")]
+fn when_player_succeeds_objective(game: &mut MockGame, player_name: String, objective_num: usize) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    let objective_name = camper_objective.get_name();
+
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Success);
+    game.broadcast(scenario_attempt);
+}
+
+#[then(regex = r"the (\d+)[a-z]+ objective should be called '(.+)'")]
+fn verify_objective_name(game: &mut MockGame, objective_num: usize, expected_name: String) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    assert_eq!(camper_objective.get_name(), expected_name);
+}
+
+#[then(regex = r"the (\d+)[a-z]+ landmark should be called '(.+)'")]
+fn verify_landmark_name(game: &mut MockGame, landmark_num: usize, expected_name: String) {
+    let landmark = game.get_all::<Landmark>()[landmark_num - 1];
+    assert_eq!(landmark.get_name(), expected_name);
+}
+
+#[then(regex = r"'(.+)' should be in the contributions list.")]
+fn verify_contribution_exists(game: &mut MockGame, expected_contribution: String) {
+    let contributions_list = game.get_one::<ContributionsList>();
+    assert!(
+        contributions_list.contains(&expected_contribution),
+        "Contribution not found. Contributions contains {:?}",
+        contributions_list
+    );
+}
+
+#[then("the linter should succeed.")]
+fn verify_linter_succeeded(game: &mut MockGame) {
+    let mut objectives_directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    objectives_directory.push("tests/assets/objectives/");
+    let locale = game.get_resource::<Locale>().clone();
+
+    let linter_output = Command::new(env!("CARGO_BIN_EXE_campsite-lint"))
+        .arg("--objectives-dir")
+        .arg(objectives_directory)
+        .arg("--locale")
+        .arg(locale.get_code())
+        .arg(&game.tiled_map_path)
+        .output()
+        .expect("verify_linter_succeeded: Could not run the linter.");
+    assert!(
+        linter_output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&linter_output.stdout)
+    );
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/localization.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
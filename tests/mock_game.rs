@@ -8,6 +8,7 @@ use bevy::render::settings::WgpuSettings;
 use bevy::sprite::SpritePlugin;
 use bevy::state::app::StatesPlugin;
 use bevy::text::TextPlugin;
+use bevy::time::TimeUpdateStrategy;
 use bevy::window::WindowResolution;
 use cucumber::World;
 
@@ -15,15 +16,24 @@ use bevy::prelude::*;
 
 use surviving_the_trip::core_logic::{
     CoreLogic, GameOverTime, MovementTime,
-    progressing::{HungerBarTime, ObjectivesDirectory},
+    choosing::VotingTime,
+    clicking::ChannelClickSender,
+    progressing::{
+        HungerBarTime, ObjectivesDirectory, ObjectivesError, ObjectivesFile, Trip, TripProgress,
+    },
     setting::*,
 };
 
 #[derive(Debug, World)]
 #[world(init = Self::new)]
+#[allow(dead_code)]
 pub struct MockGame {
     app: App,
     pub tiled_map_path: PathBuf,
+    pub trip_name: String,
+    pub objectives_file_path: PathBuf,
+    pub objectives_load_result: Option<Result<ObjectivesFile, ObjectivesError>>,
+    pub click_sender: Option<ChannelClickSender>,
 }
 
 #[allow(dead_code)]
@@ -58,12 +68,16 @@ impl MockGame {
         let movement_time = MovementTime::new(Duration::from_secs(0));
         let game_over_time = GameOverTime::new(Duration::from_secs(0));
         let hunger_bar_time = HungerBarTime::new(Duration::from_secs(0));
+        let voting_time = VotingTime::new(Duration::from_secs(0));
         app.add_plugins(CoreLogic::new(
             movement_time,
             game_over_time,
             hunger_bar_time,
+            voting_time,
         ));
 
+        app.world_mut().spawn(Camera2d);
+
         let objectives_file_path = PathBuf::from("tests/assets/objectives/");
         let objectives_directory_in_test = ObjectivesDirectory::new(objectives_file_path);
         app.insert_resource(objectives_directory_in_test);
@@ -71,6 +85,10 @@ impl MockGame {
         Self {
             app,
             tiled_map_path: PathBuf::default(),
+            trip_name: String::new(),
+            objectives_file_path: PathBuf::default(),
+            objectives_load_result: None,
+            click_sender: None,
         }
     }
 
@@ -78,6 +96,52 @@ impl MockGame {
         self.app.update();
     }
 
+    /// Moves the game forward by some amount of time in a single tick.
+    pub fn pass_time(&mut self, time_passed: Duration) {
+        // Virtual time normally refuses to jump ahead by more than a
+        // fraction of a second per tick.
+        self.get_resource_mut::<Time<Virtual>>()
+            .set_max_delta(time_passed);
+        *self.get_resource_mut::<TimeUpdateStrategy>() =
+            TimeUpdateStrategy::ManualDuration(time_passed);
+        self.tick();
+
+        *self.get_resource_mut::<TimeUpdateStrategy>() = TimeUpdateStrategy::Automatic;
+    }
+
+    /// Renders a map from the tests' map folder, waiting until it
+    /// has been fully loaded.
+    pub fn render_map(&mut self, map_name: &str) {
+        let mut tiled_map_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        tiled_map_path.push("tests/assets/maps/");
+        tiled_map_path.push(map_name);
+        self.tiled_map_path = tiled_map_path.clone();
+
+        self.broadcast(ChangeMap::new(tiled_map_path));
+        // One tick to unload the current map,
+        self.tick();
+        // And another tick to load the new map.
+        self.tick();
+    }
+
+    /// Begins a trip from the tests' trip folder, waiting until its
+    /// first campsite has been fully loaded.
+    pub fn begin_trip(&mut self, trip_name: &str) {
+        let mut trip_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        trip_path.push("tests/assets/trips/");
+        trip_path.push(trip_name);
+
+        self.app.insert_resource(TripProgress::new(
+            Trip::from_file(&trip_path).expect("begin_trip: Could not load trip file."),
+        ));
+        // One tick to begin the trip,
+        self.tick();
+        // Another to unload the current map,
+        self.tick();
+        // And another to load the first campsite.
+        self.tick();
+    }
+
     pub fn broadcast<T>(&mut self, event: T)
     where
         T: Message,
@@ -90,6 +154,17 @@ impl MockGame {
         self.tick();
     }
 
+    /// Sends every message at once, so that they are all handled
+    /// within the same tick.
+    pub fn broadcast_all<T>(&mut self, events: Vec<T>)
+    where
+        T: Message,
+    {
+        self.app.world_mut().write_message_batch(events);
+
+        self.tick();
+    }
+
     pub fn get_one<T>(&mut self) -> &T
     where
         T: Component,
@@ -175,6 +250,19 @@ impl MockGame {
             .collect()
     }
 
+    /// Returns every message of the given type sent during the last frame.
+    pub fn get_messages<T>(&mut self) -> Vec<&T>
+    where
+        T: Message,
+    {
+        let messages = self
+            .app
+            .world()
+            .get_resource::<Messages<T>>()
+            .expect("get_messages: Could not find the desired messages.");
+        messages.iter_current_update_messages().collect()
+    }
+
     pub fn get_resource<T>(&mut self) -> &T
     where
         T: Resource,
@@ -0,0 +1,336 @@
+use std::{
+    fmt,
+    net::TcpListener,
+    num::NonZeroUsize,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use cucumber::{World, given, then, when};
+use surviving_the_trip::{
+    core_logic::{
+        clicking::{ClickSource, ScriptedClick},
+        interacting::ViewerClick,
+    },
+    stream_logic::{
+        mock_heat_server::{MockClickFeed, MockHeatServer},
+        networking::{
+            DEFAULT_CLICK_CAPACITY, HeatConnectionMonitor, HeatConnectionState,
+            ReconnectionBackoff, TwitchClickListener, get_viewer_click_from_response,
+        },
+    },
+};
+use tokio::runtime::Runtime;
+
+/// How long to wait for the click listener before considering a step failed.
+const LISTENER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(World, Default)]
+pub struct NetworkTesting {
+    reconnection_backoff: ReconnectionBackoff,
+
+    mock_heat_runtime: Option<Runtime>,
+    mock_heat_url: String,
+
+    click_listener: Option<TwitchClickListener>,
+    connection_monitor: Option<HeatConnectionMonitor>,
+
+    read_click: Option<ViewerClick>,
+}
+
+impl fmt::Debug for NetworkTesting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NetworkTesting")
+            .field("reconnection_backoff", &self.reconnection_backoff)
+            .field("mock_heat_url", &self.mock_heat_url)
+            .finish()
+    }
+}
+
+impl NetworkTesting {
+    /// Connects a click listener to the given base URL.
+    fn connect_click_listener(&mut self, heat_api_url: &str) {
+        self.connect_click_listener_with_capacity(heat_api_url, DEFAULT_CLICK_CAPACITY);
+    }
+
+    /// Connects a click listener to the given base URL, holding at most the
+    /// given number of clicks.
+    fn connect_click_listener_with_capacity(
+        &mut self,
+        heat_api_url: &str,
+        click_capacity: NonZeroUsize,
+    ) {
+        let click_listener = TwitchClickListener::connect_with_capacity(
+            heat_api_url,
+            "test-channel",
+            self.reconnection_backoff.clone(),
+            click_capacity,
+        );
+        self.connection_monitor = Some(click_listener.get_connection_monitor());
+        self.click_listener = Some(click_listener);
+    }
+
+    /// Returns whether the click listener's connection reached the expected
+    /// state before timing out.
+    fn wait_for_connection_state(&self, expected_connection_state: HeatConnectionState) -> bool {
+        let connection_monitor = self
+            .connection_monitor
+            .as_ref()
+            .expect("wait_for_connection_state: The click listener never connected.");
+
+        let started_waiting = Instant::now();
+        while started_waiting.elapsed() < LISTENER_TIMEOUT {
+            if connection_monitor.get_connection_state() == expected_connection_state {
+                return true;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        false
+    }
+}
+
+#[given(regex = r"a reconnection backoff starting at (\d+) seconds? and capped at (\d+) seconds,")]
+fn given_reconnection_backoff(
+    network_testing: &mut NetworkTesting,
+    initial_delay_secs: u64,
+    max_delay_secs: u64,
+) {
+    let initial_delay = Duration::from_secs(initial_delay_secs);
+    let max_delay = Duration::from_secs(max_delay_secs);
+    network_testing.reconnection_backoff = ReconnectionBackoff::new(initial_delay, max_delay);
+}
+
+#[given(regex = r"the reconnection backoff gives up after (\d+) attempts,")]
+fn given_max_reconnection_attempts(network_testing: &mut NetworkTesting, max_attempts: usize) {
+    network_testing
+        .reconnection_backoff
+        .set_max_attempts(max_attempts);
+}
+
+#[given(
+    regex = r"a mock Heat server with (a|\d+) clicks? after (\d+) seconds at ([0-9.]+), ([0-9.]+) from '(.+)',"
+)]
+fn given_mock_heat_server(
+    network_testing: &mut NetworkTesting,
+    num_clicks: String,
+    delay_secs: u64,
+    x: f32,
+    y: f32,
+    viewer_id: String,
+) {
+    let mock_heat_runtime = Runtime::new().expect("given_mock_heat_server: Could not start tokio.");
+
+    let num_clicks = num_clicks.parse::<usize>().unwrap_or(1);
+    let mut scripted_click = ScriptedClick::new(Duration::from_secs(delay_secs), x, y);
+    scripted_click.set_viewer_id(viewer_id);
+    let click_feed = MockClickFeed::Script(vec![scripted_click; num_clicks]);
+    let mock_heat_server = mock_heat_runtime
+        .block_on(MockHeatServer::bind("127.0.0.1:0", click_feed))
+        .expect("given_mock_heat_server: Could not start the mock Heat server.");
+
+    network_testing.mock_heat_url = mock_heat_server
+        .get_url()
+        .expect("given_mock_heat_server: Could not find the mock Heat server's address.");
+    mock_heat_runtime.spawn(mock_heat_server.run());
+    network_testing.mock_heat_runtime = Some(mock_heat_runtime);
+}
+
+#[when("the click listener connects to the mock Heat server,")]
+fn when_click_listener_connects_to_mock(network_testing: &mut NetworkTesting) {
+    let mock_heat_url = network_testing.mock_heat_url.clone();
+    network_testing.connect_click_listener(&mock_heat_url);
+}
+
+#[when(
+    regex = r"the click listener connects to the mock Heat server holding at most (\d+) clicks,"
+)]
+fn when_click_listener_connects_with_capacity(
+    network_testing: &mut NetworkTesting,
+    click_capacity: usize,
+) {
+    let click_capacity = NonZeroUsize::new(click_capacity)
+        .expect("when_click_listener_connects_with_capacity: The capacity cannot be 0.");
+    let mock_heat_url = network_testing.mock_heat_url.clone();
+    network_testing.connect_click_listener_with_capacity(&mock_heat_url, click_capacity);
+}
+
+#[when("the click listener connects to an address nobody is listening on,")]
+fn when_click_listener_connects_to_nobody(network_testing: &mut NetworkTesting) {
+    // Asking for any free port and letting it go right away leaves
+    // an address that nothing is listening on.
+    let unused_address = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("when_click_listener_connects_to_nobody: Could not find a free port.");
+    network_testing.connect_click_listener(&format!("ws://{}", unused_address));
+}
+
+#[when(regex = r"the Heat frame '(.+)' is read,")]
+fn when_heat_frame_read(network_testing: &mut NetworkTesting, heat_frame: String) {
+    let heat_response = serde_json::from_str(&heat_frame)
+        .expect("when_heat_frame_read: The Heat frame is not valid JSON.");
+    network_testing.read_click = get_viewer_click_from_response(heat_response);
+}
+
+#[then("the click listener should be connected.")]
+fn verify_click_listener_connected(network_testing: &mut NetworkTesting) {
+    assert!(network_testing.wait_for_connection_state(HeatConnectionState::Connected));
+}
+
+#[then("the click listener should have failed to connect.")]
+fn verify_click_listener_failed(network_testing: &mut NetworkTesting) {
+    assert!(network_testing.wait_for_connection_state(HeatConnectionState::Failed));
+}
+
+#[then(regex = r"the click listener should receive a click at ([0-9.]+), ([0-9.]+) from '(.+)'.")]
+fn verify_click_listener_received_click(
+    network_testing: &mut NetworkTesting,
+    expected_x: f32,
+    expected_y: f32,
+    expected_viewer_id: String,
+) {
+    let click_listener = network_testing
+        .click_listener
+        .as_mut()
+        .expect("verify_click_listener_received_click: The click listener never connected.");
+
+    let started_waiting = Instant::now();
+    while started_waiting.elapsed() < LISTENER_TIMEOUT {
+        if let Some(viewer_click) = click_listener.read() {
+            assert_eq!(expected_x, viewer_click.get_x());
+            assert_eq!(expected_y, viewer_click.get_y());
+            assert_eq!(&expected_viewer_id, viewer_click.get_viewer().get_id());
+            return;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    panic!("verify_click_listener_received_click: No click was received.");
+}
+
+#[then(regex = r"the frame should be read as a click at ([0-9.]+), ([0-9.]+) from '(.+)'.")]
+fn verify_frame_read_as_click(
+    network_testing: &mut NetworkTesting,
+    expected_x: f32,
+    expected_y: f32,
+    expected_viewer_id: String,
+) {
+    let viewer_click = network_testing
+        .read_click
+        .as_ref()
+        .expect("verify_frame_read_as_click: The frame was not read as a click.");
+
+    assert_eq!(expected_x, viewer_click.get_x());
+    assert_eq!(expected_y, viewer_click.get_y());
+    assert_eq!(&expected_viewer_id, viewer_click.get_viewer().get_id());
+}
+
+#[then(regex = r"the clicking viewer should be called '(.+)'.")]
+fn verify_clicking_viewer_name(network_testing: &mut NetworkTesting, expected_name: String) {
+    let viewer_click = network_testing
+        .read_click
+        .as_ref()
+        .expect("verify_clicking_viewer_name: The frame was not read as a click.");
+
+    assert_eq!(&expected_name, viewer_click.get_viewer().get_name());
+}
+
+#[then("the frame should not be read as a click.")]
+fn verify_frame_not_read_as_click(network_testing: &mut NetworkTesting) {
+    assert!(network_testing.read_click.is_none());
+}
+
+#[then(regex = r"the click listener should drop (\d+) clicks.")]
+fn verify_click_listener_dropped_clicks(
+    network_testing: &mut NetworkTesting,
+    expected_num_dropped: usize,
+) {
+    let connection_monitor = network_testing
+        .connection_monitor
+        .as_ref()
+        .expect("verify_click_listener_dropped_clicks: The click listener never connected.");
+
+    let started_waiting = Instant::now();
+    while started_waiting.elapsed() < LISTENER_TIMEOUT {
+        if connection_monitor.get_num_dropped_clicks() >= expected_num_dropped {
+            break;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(
+        connection_monitor.get_num_dropped_clicks(),
+        expected_num_dropped
+    );
+}
+
+#[then(regex = r"the click listener should have (\d+) clicks waiting.")]
+fn verify_click_listener_clicks_waiting(
+    network_testing: &mut NetworkTesting,
+    expected_num_waiting: usize,
+) {
+    let click_listener = network_testing
+        .click_listener
+        .as_mut()
+        .expect("verify_click_listener_clicks_waiting: The click listener never connected.");
+
+    let mut num_waiting = 0;
+    while click_listener.read().is_some() {
+        num_waiting += 1;
+    }
+
+    assert_eq!(num_waiting, expected_num_waiting);
+}
+
+#[then(regex = r"the delay before reconnection attempt (\d+) should be (\d+) seconds?.")]
+fn verify_reconnection_delay(
+    network_testing: &mut NetworkTesting,
+    attempt: usize,
+    expected_delay_secs: u64,
+) {
+    let expected_delay = Duration::from_secs(expected_delay_secs);
+    let actual_delay = network_testing.reconnection_backoff.get_delay(attempt);
+    assert_eq!(expected_delay, actual_delay);
+}
+
+#[then(
+    regex = r"the jittered delay before reconnection attempt (\d+) should be between (\d+) and (\d+) seconds."
+)]
+fn verify_jittered_reconnection_delay(
+    network_testing: &mut NetworkTesting,
+    attempt: usize,
+    min_delay_secs: u64,
+    max_delay_secs: u64,
+) {
+    let min_delay = Duration::from_secs(min_delay_secs);
+    let max_delay = Duration::from_secs(max_delay_secs);
+    let actual_delay = network_testing
+        .reconnection_backoff
+        .get_jittered_delay(attempt);
+    assert!(
+        min_delay <= actual_delay && actual_delay <= max_delay,
+        "Jittered delay {:?} is outside of the expected range.",
+        actual_delay
+    );
+}
+
+#[then(regex = r"reconnection attempt (\d+) should still be made.")]
+fn verify_reconnection_attempted(network_testing: &mut NetworkTesting, attempt: usize) {
+    assert!(!network_testing.reconnection_backoff.has_given_up(attempt));
+}
+
+#[then(regex = r"reconnection attempt (\d+) should not be made.")]
+fn verify_reconnection_not_attempted(network_testing: &mut NetworkTesting, attempt: usize) {
+    assert!(network_testing.reconnection_backoff.has_given_up(attempt));
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/networking.feature");
+
+    futures::executor::block_on(NetworkTesting::run(feature_path));
+}
@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use bevy::prelude::Text;
+use surviving_the_trip::core_logic::{
+    phrasing::Locale,
+    progressing::{
+        CamperObjective, ContributionsList, ObjectivesDirectory, ObjectivesErrorMessage,
+        ObjectivesFile,
+    },
+    setting::LogicalCoordinates,
+    traveling::{Bus, BusExit, Pathfinding},
+};
+
+#[given(regex = r"the objectives file '(.+)',")]
+fn given_objectives_file(game: &mut MockGame, objectives_file_name: String) {
+    let mut objectives_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    objectives_file_path.push("tests/assets/");
+    objectives_file_path.push(objectives_file_name);
+
+    game.objectives_file_path = objectives_file_path;
+}
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(game: &mut MockGame, locale_code: String) {
+    *game.get_resource_mut::<Locale>() = Locale::new(locale_code);
+}
+
+#[given(regex = r"the objectives directory '(.+)',")]
+fn given_objectives_directory(game: &mut MockGame, objectives_directory_name: String) {
+    let mut objectives_directory_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    objectives_directory_path.push("tests/assets/");
+    objectives_directory_path.push(objectives_directory_name);
+
+    *game.get_resource_mut::<ObjectivesDirectory>() =
+        ObjectivesDirectory::new(objectives_directory_path);
+}
+
+#[when("the objectives file is loaded,")]
+fn when_objectives_file_loaded(game: &mut MockGame) {
+    let objectives_load_result = ObjectivesFile::from_file(&game.objectives_file_path);
+    game.objectives_load_result = Some(objectives_load_result);
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[then("the objectives file should load.")]
+fn verify_objectives_file_loaded(game: &mut MockGame) {
+    let objectives_load_result = game
+        .objectives_load_result
+        .as_ref()
+        .expect("verify_objectives_file_loaded: The objectives file was never loaded.");
+    assert!(
+        objectives_load_result.is_ok(),
+        "{:?}",
+        objectives_load_result
+    );
+}
+
+#[then(regex = r"the objectives file should fail at '(.+)'.")]
+fn verify_objectives_file_failed_at(game: &mut MockGame, expected_json_path: String) {
+    let objectives_error = game
+        .objectives_load_result
+        .as_ref()
+        .expect("verify_objectives_file_failed_at: The objectives file was never loaded.")
+        .as_ref()
+        .expect_err("verify_objectives_file_failed_at: The objectives file loaded fine.");
+
+    assert_eq!(*objectives_error.get_json_path(), expected_json_path);
+    assert_eq!(*objectives_error.get_file(), game.objectives_file_path);
+}
+
+#[then(regex = r"the problem should mention '(.+)'.")]
+fn verify_objectives_problem(game: &mut MockGame, expected_problem: String) {
+    let objectives_error = game
+        .objectives_load_result
+        .as_ref()
+        .expect("verify_objectives_problem: The objectives file was never loaded.")
+        .as_ref()
+        .expect_err("verify_objectives_problem: The objectives file loaded fine.");
+
+    assert!(
+        objectives_error.get_problem().contains(&expected_problem),
+        "{}",
+        objectives_error
+    );
+}
+
+#[then("the objectives error should be shown.")]
+fn verify_objectives_error_shown(game: &mut MockGame) {
+    let objectives_error_messages = game.get_all::<ObjectivesErrorMessage>();
+    assert_eq!(objectives_error_messages.len(), 1);
+}
+
+#[then(regex = r"the objectives error should read '(.+)'")]
+fn verify_objectives_error_text(game: &mut MockGame, expected_text: String) {
+    let (objectives_error_text, _error_message) =
+        game.get_all_containing::<Text, ObjectivesErrorMessage>()[0];
+    assert!(
+        objectives_error_text.0.starts_with(&expected_text),
+        "{}",
+        objectives_error_text.0
+    );
+}
+
+#[then(regex = r"there should be (\d+) objectives.")]
+fn verify_number_of_objectives(game: &mut MockGame, expected_num_objectives: usize) {
+    let num_objectives = game.get_all::<CamperObjective>().len();
+    assert_eq!(num_objectives, expected_num_objectives);
+}
+
+#[then("there should be an empty contributions list.")]
+fn verify_empty_contributions_list(game: &mut MockGame) {
+    let contributions_list = game.get_one::<ContributionsList>();
+    assert_eq!(contributions_list.len(), 0);
+}
+
+#[then("the bus should be heading to the exit.")]
+fn verify_bus_heading_to_exit(game: &mut MockGame) {
+    // One tick for the bus to notice there is nothing left to do.
+    game.tick();
+
+    let bus_exit_location = *game.get_with::<LogicalCoordinates, BusExit>();
+    let bus_found = game.get_all_containing::<Pathfinding, Bus>();
+    let bus_target_location = *bus_found
+        .first()
+        .expect("verify_bus_heading_to_exit: The bus is not going anywhere.")
+        .0
+        .get_destination();
+    assert_eq!(bus_exit_location, bus_target_location);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/objectives.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
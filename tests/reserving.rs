@@ -0,0 +1,206 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use cucumber::{World, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    interacting::{CamperInformation, ObjectiveAttempt, ViewerIdentity},
+    reserving::{BusDoorQueue, TileReservations},
+    setting::{LogicalCoordinates, WorldTileDimensions},
+    traveling::{CamperJourney, Graph, Movement, Pathfinding},
+};
+
+/// Returns the spot written as "x, y" in a step.
+fn parse_spot(spot: &str) -> LogicalCoordinates {
+    let (x, y) = spot
+        .split_once(", ")
+        .expect("parse_spot: Spot is not written as x, y.");
+
+    LogicalCoordinates::new(
+        x.parse().expect("parse_spot: x is not a number."),
+        y.parse().expect("parse_spot: y is not a number."),
+        0,
+    )
+}
+
+/// Returns the path a viewer's camper would take between two spots around
+/// every spot reserved so far.
+fn get_reserved_path(
+    game: &mut MockGame,
+    player_name: &str,
+    source: &str,
+    destination: &str,
+) -> Pathfinding {
+    // The reservations are taken out of the game while planning, since
+    // the traversal graph can only be borrowed from the game on its own.
+    let tile_reservations = std::mem::take(&mut *game.get_resource_mut::<TileReservations>());
+    let reserved_path = Pathfinding::reserved_path(
+        &parse_spot(source),
+        &parse_spot(destination),
+        game.get_one::<Graph>(),
+        &tile_reservations,
+        &ViewerIdentity::new(player_name.to_string()),
+    )
+    .expect("get_reserved_path: Could not find a path.");
+    *game.get_resource_mut::<TileReservations>() = tile_reservations;
+
+    reserved_path
+}
+
+/// Returns where every camper out on the campsite is standing, leaving out
+/// those hidden away in the meadows.
+fn get_visible_camper_locations(game: &mut MockGame) -> Vec<LogicalCoordinates> {
+    game.get_all_containing::<CamperJourney, LogicalCoordinates>()
+        .into_iter()
+        .filter(|(camper_journey, _)| **camper_journey != CamperJourney::InMeadows)
+        .map(|(_, camper_location)| *camper_location)
+        .collect()
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[when(regex = r"(\d+) viewers attempt an objective at the same time,")]
+fn when_viewers_attempt_objective(game: &mut MockGame, num_viewers: usize) {
+    let objective_attempts = (1..=num_viewers)
+        .map(|viewer_num| {
+            let viewer = ViewerIdentity::new(format!("Player {}", viewer_num));
+            ObjectiveAttempt::new(viewer, String::from("Find some berries"))
+        })
+        .collect();
+
+    game.broadcast_all(objective_attempts);
+}
+
+#[when("a step passes,")]
+fn when_step_passes(game: &mut MockGame) {
+    // Campers move one tile per tick in tests.
+    game.tick();
+}
+
+#[when(regex = r"'(.+)' reserves the cheapest path from (\d+, \d+) to (\d+, \d+),")]
+fn when_player_reserves_path(
+    game: &mut MockGame,
+    player_name: String,
+    source: String,
+    destination: String,
+) {
+    let cheapest_path = Pathfinding::cheapest_path(
+        &parse_spot(&source),
+        &parse_spot(&destination),
+        game.get_one::<Graph>(),
+        Movement::Straight,
+    )
+    .expect("when_player_reserves_path: Could not find a path.");
+
+    let viewer = ViewerIdentity::new(player_name);
+    game.get_resource_mut::<TileReservations>()
+        .reserve_path(&viewer, &cheapest_path.get_locations());
+}
+
+#[then(regex = r"there should be (\d+) campers? outside of the bus.")]
+fn verify_num_campers_out(game: &mut MockGame, expected_num_campers: usize) {
+    let num_campers = game.get_all::<CamperInformation>().len();
+
+    assert_eq!(num_campers, expected_num_campers);
+}
+
+#[then(regex = r"(\d+) campers? should be waiting in the bus.")]
+fn verify_num_campers_waiting(game: &mut MockGame, expected_num_campers: usize) {
+    let num_campers_waiting = game.get_resource::<BusDoorQueue>().len();
+
+    assert_eq!(num_campers_waiting, expected_num_campers);
+}
+
+#[then("every camper should reach the meadows without sharing a spot with another camper.")]
+fn verify_campers_never_share_spot(game: &mut MockGame) {
+    // Campers move one tile per tick in tests, so no path should ever
+    // take longer than there are tiles, other than waiting for others.
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+    for _i in 0..max_ticks {
+        let camper_locations = get_visible_camper_locations(game);
+        let unique_camper_locations: HashSet<&LogicalCoordinates> =
+            camper_locations.iter().collect();
+        assert_eq!(
+            unique_camper_locations.len(),
+            camper_locations.len(),
+            "verify_campers_never_share_spot: Campers are standing on the same spot."
+        );
+
+        if camper_locations.is_empty() && game.get_resource::<BusDoorQueue>().is_empty() {
+            return;
+        }
+
+        game.tick();
+    }
+
+    panic!("verify_campers_never_share_spot: Campers never reached the meadows.");
+}
+
+#[then(
+    regex = r"the path reserved for '(.+)' from (\d+, \d+) to (\d+, \d+) should never run into '(.+)'."
+)]
+fn verify_reserved_path_avoids(
+    game: &mut MockGame,
+    player_name: String,
+    source: String,
+    destination: String,
+    other_player_name: String,
+) {
+    let reserved_path = get_reserved_path(game, &player_name, &source, &destination);
+
+    let other_viewer = ViewerIdentity::new(other_player_name);
+    let tile_reservations = game.get_resource::<TileReservations>();
+    let current_step = tile_reservations.get_current_step();
+    let is_other_at = |location: &LogicalCoordinates, step_offset: usize| {
+        tile_reservations.get_reserver(location, current_step + step_offset) == Some(&other_viewer)
+    };
+
+    let path_locations = reserved_path.get_locations();
+    for (step_offset, location) in path_locations.iter().enumerate() {
+        assert!(
+            !is_other_at(location, step_offset),
+            "verify_reserved_path_avoids: Both campers stand on {}, {} at step {}.",
+            location.get_x(),
+            location.get_y(),
+            step_offset
+        );
+    }
+
+    for (step_offset, step) in path_locations.windows(2).enumerate() {
+        let is_swapping =
+            is_other_at(&step[1], step_offset) && is_other_at(&step[0], step_offset + 1);
+        assert!(
+            !is_swapping,
+            "verify_reserved_path_avoids: Both campers swap spots at step {}.",
+            step_offset
+        );
+    }
+}
+
+#[then(
+    regex = r"the path reserved for '(.+)' from (\d+, \d+) to (\d+, \d+) should take (\d+) steps."
+)]
+fn verify_reserved_path_steps(
+    game: &mut MockGame,
+    player_name: String,
+    source: String,
+    destination: String,
+    expected_num_steps: usize,
+) {
+    let reserved_path = get_reserved_path(game, &player_name, &source, &destination);
+
+    assert_eq!(reserved_path.get_locations().len() - 1, expected_num_steps);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/reserving.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
@@ -0,0 +1,188 @@
+use std::path::PathBuf;
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    interacting::{ScenarioAttempt, ScenarioResult, ViewerIdentity},
+    progressing::{HungerBar, Landmark, ScenarioChoice},
+    resolving::{CamperRecord, CamperRecords, GameRng, ScenarioChoiceMade, get_success_chance},
+};
+
+/// Returns the objective and choice found in some scenario of a landmark
+/// on the currently loaded campsite.
+fn get_landmark_choice(
+    game: &mut MockGame,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+) -> (String, ScenarioChoice) {
+    let all_landmarks = game.get_all::<Landmark>();
+    let selected_scenario = all_landmarks[landmark_num - 1].get_scenario(scenario_num - 1);
+    let selected_choice = selected_scenario.get_choice(choice_num - 1).clone();
+
+    (selected_scenario.get_type(), selected_choice)
+}
+
+#[given(regex = r"the outcome seed (\d+),")]
+fn given_outcome_seed(game: &mut MockGame, seed: u64) {
+    *game.get_resource_mut::<GameRng>() = GameRng::from_seed(seed);
+}
+
+#[given(regex = r"'(.+)' has succeeded (\d+) times and failed (\d+) times,")]
+fn given_camper_record(
+    game: &mut MockGame,
+    player_name: String,
+    successes: usize,
+    failures: usize,
+) {
+    let player = ViewerIdentity::new(player_name);
+    game.get_resource_mut::<CamperRecords>()
+        .set_record(player, CamperRecord::new(successes, failures));
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[when(regex = r"the hunger bar is set to (\d+)% full,")]
+fn when_hunger_bar_set(game: &mut MockGame, percentage: usize) {
+    game.get_resource_mut::<HungerBar>()
+        .set_percentage(percentage);
+}
+
+#[when(
+    regex = r"'(.+)' makes the (\d+)[a-z]+ choice from the (\d+)[a-z]+ scenario in the (\d+)[a-z]+ landmark with (\d+) votes,"
+)]
+fn when_player_makes_choice(
+    game: &mut MockGame,
+    player_name: String,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+    num_votes: usize,
+) {
+    let (objective_name, selected_choice) =
+        get_landmark_choice(game, choice_num, scenario_num, landmark_num);
+
+    let player = ViewerIdentity::new(player_name);
+    let scenario_choice_made =
+        ScenarioChoiceMade::new(player, objective_name, selected_choice, num_votes);
+    game.broadcast(scenario_choice_made);
+}
+
+#[then(
+    regex = r"the chance of '(.+)' succeeding with the (\d+)[a-z]+ choice from the (\d+)[a-z]+ scenario in the (\d+)[a-z]+ landmark with (\d+) votes should be (\d+)%."
+)]
+fn verify_success_chance(
+    game: &mut MockGame,
+    player_name: String,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+    num_votes: usize,
+    expected_success_chance: usize,
+) {
+    let (_, selected_choice) = get_landmark_choice(game, choice_num, scenario_num, landmark_num);
+    let hunger_percentage = game.get_resource::<HungerBar>().get_current_percentage();
+    let camper_record = game
+        .get_resource::<CamperRecords>()
+        .get_record(&ViewerIdentity::new(player_name));
+
+    let success_chance = get_success_chance(
+        &selected_choice,
+        hunger_percentage,
+        num_votes,
+        &camper_record,
+    );
+    assert_eq!(success_chance, expected_success_chance);
+}
+
+#[then(
+    regex = r"'(.+)' should have attempted '(.+)' with the (\d+)[a-z]+ choice from the (\d+)[a-z]+ scenario in the (\d+)[a-z]+ landmark."
+)]
+fn verify_scenario_attempted(
+    game: &mut MockGame,
+    player_name: String,
+    expected_objective: String,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+) {
+    let (_, expected_choice) = get_landmark_choice(game, choice_num, scenario_num, landmark_num);
+    let player = ViewerIdentity::new(player_name);
+
+    let scenario_attempts = game.get_messages::<ScenarioAttempt>();
+    let scenario_attempt = scenario_attempts
+        .iter()
+        .find(|scenario_attempt| *scenario_attempt.get_viewer() == player)
+        .expect("verify_scenario_attempted: The player never attempted a scenario.");
+
+    assert_eq!(*scenario_attempt.get_objective(), expected_objective);
+    assert_eq!(scenario_attempt.get_choice(), Some(&expected_choice));
+}
+
+#[then(regex = r"'(.+)' should have (\d+) past results.")]
+fn verify_num_past_results(game: &mut MockGame, player_name: String, expected_num_results: usize) {
+    let camper_record = game
+        .get_resource::<CamperRecords>()
+        .get_record(&ViewerIdentity::new(player_name));
+
+    let num_results = camper_record.get_successes() + camper_record.get_failures();
+    assert_eq!(num_results, expected_num_results);
+}
+
+#[then(
+    regex = r"'(.+)' making the (\d+)[a-z]+ choice from the (\d+)[a-z]+ scenario in the (\d+)[a-z]+ landmark (\d+) times with the seed (\d+) should turn out the same every time."
+)]
+fn verify_outcomes_reproducible(
+    game: &mut MockGame,
+    player_name: String,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+    num_attempts: usize,
+    seed: u64,
+) {
+    let (objective_name, selected_choice) =
+        get_landmark_choice(game, choice_num, scenario_num, landmark_num);
+    let player = ViewerIdentity::new(player_name);
+
+    let mut all_outcomes: Vec<Vec<ScenarioResult>> = Vec::new();
+    for _run in 0..2 {
+        *game.get_resource_mut::<GameRng>() = GameRng::from_seed(seed);
+        *game.get_resource_mut::<CamperRecords>() = CamperRecords::default();
+
+        let scenario_choices_made = (0..num_attempts)
+            .map(|_attempt| {
+                ScenarioChoiceMade::new(
+                    player.clone(),
+                    objective_name.clone(),
+                    selected_choice.clone(),
+                    1,
+                )
+            })
+            .collect();
+        game.broadcast_all(scenario_choices_made);
+
+        let outcomes = game
+            .get_messages::<ScenarioAttempt>()
+            .iter()
+            .map(|scenario_attempt| scenario_attempt.get_status())
+            .collect::<Vec<ScenarioResult>>();
+        assert_eq!(outcomes.len(), num_attempts);
+        all_outcomes.push(outcomes);
+    }
+
+    assert_eq!(all_outcomes[0], all_outcomes[1]);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/resolving.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
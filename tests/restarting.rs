@@ -0,0 +1,177 @@
+use std::{path::PathBuf, time::Duration};
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    CampersState, GameOverTime,
+    interacting::{CamperInformation, ScenarioAttempt, ScenarioResult, ViewerIdentity},
+    phrasing::Locale,
+    progressing::{CamperObjective, CampsiteCleared, ContributionsList, HungerBar, TripProgress},
+    restarting::GameOverCountdown,
+};
+
+use bevy::prelude::Text;
+
+#[given(regex = r"the locale '(.+)',")]
+fn given_locale(game: &mut MockGame, locale_code: String) {
+    *game.get_resource_mut::<Locale>() = Locale::new(locale_code);
+}
+
+#[given(regex = r"the game restarts (\d+) seconds after the campers starve,")]
+fn given_game_over_time(game: &mut MockGame, num_seconds: u64) {
+    *game.get_resource_mut::<GameOverTime>() = GameOverTime::new(Duration::from_secs(num_seconds));
+}
+
+#[given("the hunger bar only goes down when campers fail,")]
+fn given_hunger_bar_never_decreases(game: &mut MockGame) {
+    game.get_resource_mut::<HungerBar>()
+        .set_percentage_decrease(0);
+}
+
+#[given(regex = r"a trip called '(.+)',")]
+fn given_trip_file(game: &mut MockGame, trip_name: String) {
+    game.trip_name = trip_name;
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[when("the trip begins,")]
+fn when_trip_begins(game: &mut MockGame) {
+    let trip_name = game.trip_name.clone();
+    game.begin_trip(&trip_name);
+}
+
+#[when(regex = r"'(.+)' succeeds the (\d+)[a-z]+ objective,")]
+fn when_player_succeeds_objective(game: &mut MockGame, player_name: String, objective_num: usize) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    let objective_name = camper_objective.get_name();
+
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Success);
+    game.broadcast(scenario_attempt);
+}
+
+#[when("the campsite is cleared,")]
+fn when_campsite_cleared(game: &mut MockGame) {
+    game.broadcast(CampsiteCleared);
+    // One tick to unload the cleared campsite,
+    game.tick();
+    // And another to load the next one.
+    game.tick();
+}
+
+#[when("the campers starve,")]
+fn when_campers_starve(game: &mut MockGame) {
+    game.get_resource_mut::<HungerBar>().set_percentage(0);
+    // One tick to notice the hunger bar is empty,
+    game.tick();
+    // And another for the campers to die.
+    game.tick();
+}
+
+#[when(regex = r"(\d+) seconds have passed,")]
+fn when_seconds_pass(game: &mut MockGame, num_seconds: u64) {
+    game.pass_time(Duration::from_secs(num_seconds));
+}
+
+#[when("the countdown runs out,")]
+fn when_countdown_runs_out(game: &mut MockGame) {
+    let game_over_duration = game.get_resource::<GameOverTime>().get_timer().duration();
+    game.pass_time(game_over_duration);
+    // One tick to bring the campers back to life,
+    game.tick();
+    // Another to unload the campsite they died at,
+    game.tick();
+    // And another to load the first campsite again.
+    game.tick();
+}
+
+#[then(regex = r"all campers should be (alive|dead).")]
+fn verify_campers_state(game: &mut MockGame, expected_campers_state: String) {
+    let expected_campers_state = match expected_campers_state.as_str() {
+        "alive" => CampersState::Alive,
+        _ => CampersState::Dead,
+    };
+
+    let campers_state = *game.get_game_state::<CampersState>().get();
+    assert_eq!(campers_state, expected_campers_state);
+}
+
+#[then(regex = r"the countdown should read '(.+)'")]
+fn verify_countdown_text(game: &mut MockGame, expected_text: String) {
+    let (countdown_text, _marker) = game.get_all_containing::<Text, GameOverCountdown>()[0];
+    assert!(
+        countdown_text.0.contains(&expected_text),
+        "{}",
+        countdown_text.0
+    );
+}
+
+#[then("the countdown should not be shown.")]
+fn verify_countdown_not_shown(game: &mut MockGame) {
+    assert!(game.get_all::<GameOverCountdown>().is_empty());
+}
+
+#[then(regex = r"the hunger bar should be at (\d+)%.")]
+fn verify_hunger_bar_percentage(game: &mut MockGame, expected_percentage: usize) {
+    let hunger_bar = game.get_resource::<HungerBar>();
+    assert_eq!(hunger_bar.get_current_percentage(), expected_percentage);
+}
+
+#[then("there should be no campers.")]
+fn verify_no_campers(game: &mut MockGame) {
+    assert!(game.get_all::<CamperInformation>().is_empty());
+}
+
+#[then(regex = r"there should be (\d+) objectives.")]
+fn verify_num_objectives(game: &mut MockGame, expected_num_objectives: usize) {
+    assert_eq!(
+        game.get_all::<CamperObjective>().len(),
+        expected_num_objectives
+    );
+}
+
+#[then(regex = r"there should be (\d+) contributions.")]
+fn verify_num_contributions(game: &mut MockGame, expected_num_contributions: usize) {
+    assert_eq!(
+        game.get_one::<ContributionsList>().len(),
+        expected_num_contributions
+    );
+}
+
+#[then(regex = r"the current campsite should be '(.+)'.")]
+fn verify_current_campsite(game: &mut MockGame, expected_campsite_name: String) {
+    let current_campsite_name = game
+        .get_resource::<TripProgress>()
+        .get_current_campsite()
+        .expect("verify_current_campsite: The campers are not at any campsite.")
+        .get_name();
+    assert_eq!(current_campsite_name, expected_campsite_name);
+}
+
+#[then(regex = r"(\d+) campsites should have been survived.")]
+fn verify_campsites_survived(game: &mut MockGame, expected_campsites_survived: usize) {
+    let campsites_survived = game.get_resource::<TripProgress>().get_campsites_survived();
+    assert_eq!(campsites_survived, expected_campsites_survived);
+}
+
+#[then(regex = r"there should be (\d+) total contributions.")]
+fn verify_total_contributions(game: &mut MockGame, expected_total_contributions: usize) {
+    let total_contributions = game
+        .get_resource::<TripProgress>()
+        .get_total_contributions();
+    assert_eq!(total_contributions, expected_total_contributions);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/restarting.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
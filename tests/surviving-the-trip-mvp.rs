@@ -1,5 +1,6 @@
-use std::path::PathBuf;
+use std::{collections::HashSet, path::PathBuf};
 
+use bevy::prelude::{Children, Visibility};
 use cucumber::{World, given, then, when};
 
 mod mock_game;
@@ -7,10 +8,12 @@ use mock_game::*;
 
 use surviving_the_trip::core_logic::{
     CampersState,
-    interacting::{CamperInformation, ObjectiveAttempt, ScenarioAttempt, ScenarioResult},
-    progressing::{CamperObjective, ContributionsList, HungerBar, Landmark},
+    interacting::{
+        CamperInformation, ObjectiveAttempt, ScenarioAttempt, ScenarioResult, ViewerIdentity,
+    },
+    progressing::{CamperObjective, CampsiteCleared, ContributionsList, HungerBar, Landmark},
     setting::{ChangeMap, LogicalCoordinates, WorldTileDimensions},
-    traveling::{MeadowEntrance, OutsideOfBus, Pathfinding},
+    traveling::{Bus, BusExit, CamperJourney, MeadowEntrance, OutsideOfBus, Pathfinding},
 };
 
 /// Returns a CampersState parsed from an expected string in the form of
@@ -58,6 +61,11 @@ fn load_campsite_map(game: &mut MockGame) {
     game.tick();
 }
 
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_other_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
 #[when(regex = r"(\d+) seconds have passed,")]
 fn tick_per_second(game: &mut MockGame, seconds_to_pass: usize) {
     for _i in 0..seconds_to_pass {
@@ -70,7 +78,8 @@ fn simulate_player_failing(game: &mut MockGame, player_name: String, scenario_nu
     let scenario_objective = game.get_all::<CamperObjective>()[scenario_num - 1];
     let objective_name = scenario_objective.get_name();
 
-    let scenario_attempt = ScenarioAttempt::new(player_name, objective_name, ScenarioResult::Fail);
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Fail);
     game.broadcast(scenario_attempt);
 }
 
@@ -79,8 +88,41 @@ fn simulate_player_succeeding(game: &mut MockGame, player_name: String, scenario
     let scenario_objective = game.get_all::<CamperObjective>()[scenario_num - 1];
     let objective_name = scenario_objective.get_name();
 
-    let scenario_attempt =
-        ScenarioAttempt::new(player_name, objective_name, ScenarioResult::Success);
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Success);
+    game.broadcast(scenario_attempt);
+}
+
+#[when(regex = r"^'(.+)' succeeds at '(.+)',$")]
+fn simulate_player_succeeding_at(game: &mut MockGame, player_name: String, objective_name: String) {
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Success);
+    game.broadcast(scenario_attempt);
+}
+
+#[when(
+    regex = r"'(.+)' (succeeds|fails) with the (\d+)[a-z]+ choice from the (\d+)[a-z]+ scenario in the (\d+)[a-z]+ landmark,"
+)]
+fn simulate_player_choosing(
+    game: &mut MockGame,
+    player_name: String,
+    result: String,
+    choice_num: usize,
+    scenario_num: usize,
+    landmark_num: usize,
+) {
+    let all_landmarks = game.get_all::<Landmark>();
+    let selected_scenario = all_landmarks[landmark_num - 1].get_scenario(scenario_num - 1);
+    let objective_name = selected_scenario.get_type();
+    let selected_choice = selected_scenario.get_choice(choice_num - 1).clone();
+
+    let scenario_result = match result.as_str() {
+        "succeeds" => ScenarioResult::Success,
+        _ => ScenarioResult::Fail,
+    };
+    let player = ViewerIdentity::new(player_name);
+    let mut scenario_attempt = ScenarioAttempt::new(player, objective_name, scenario_result);
+    scenario_attempt.set_choice(selected_choice);
     game.broadcast(scenario_attempt);
 }
 
@@ -89,10 +131,107 @@ fn when_player_attempts_objective(game: &mut MockGame, player_name: String, obje
     let scenario_objective = game.get_all::<CamperObjective>()[objective_num - 1];
     let objective_name = scenario_objective.get_name();
 
-    let objective_attempt = ObjectiveAttempt::new(player_name, objective_name);
+    let player = ViewerIdentity::new(player_name);
+    let objective_attempt = ObjectiveAttempt::new(player, objective_name);
     game.broadcast(objective_attempt);
 }
 
+#[when(regex = r"'(.+)' finishes traveling,")]
+fn when_player_finishes_traveling(game: &mut MockGame, player_name: String) {
+    // Campers move one tile per tick in tests, so no path should ever
+    // take longer than visiting every tile on the map.
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+
+    for _i in 0..max_ticks {
+        let camper_still_traveling = game
+            .get_all_containing::<Pathfinding, CamperInformation>()
+            .iter()
+            .any(|camper| *camper.1.get_camper_name() == player_name);
+        if !camper_still_traveling {
+            return;
+        }
+
+        game.tick();
+    }
+
+    panic!("when_player_finishes_traveling: Camper never finished traveling.");
+}
+
+#[when("all objectives are completed,")]
+fn when_all_objectives_completed(game: &mut MockGame) {
+    // Everyone still on their way needs to reach the meadows before
+    // their scenario can be resolved.
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+    for _i in 0..max_ticks {
+        let campers_heading_out = game
+            .get_all::<CamperJourney>()
+            .iter()
+            .any(|journey| **journey == CamperJourney::HeadingToMeadows);
+        if !campers_heading_out {
+            break;
+        }
+
+        game.tick();
+    }
+
+    let mut campers_in_meadows: Vec<ViewerIdentity> = game
+        .get_all_containing::<CamperJourney, CamperInformation>()
+        .iter()
+        .filter(|camper| *camper.0 == CamperJourney::InMeadows)
+        .map(|camper| camper.1.get_viewer().clone())
+        .collect();
+    if campers_in_meadows.is_empty() {
+        campers_in_meadows.push(ViewerIdentity::new(String::from("Camper")));
+    }
+
+    let objective_names: Vec<String> = game
+        .get_all::<CamperObjective>()
+        .iter()
+        .map(|objective| objective.get_name())
+        .collect();
+    let scenario_attempts = objective_names
+        .into_iter()
+        .zip(campers_in_meadows.iter().cycle())
+        .map(|(objective_name, camper)| {
+            ScenarioAttempt::new(camper.clone(), objective_name, ScenarioResult::Success)
+        })
+        .collect();
+    game.broadcast_all(scenario_attempts);
+}
+
+#[when("all campers are on the bus,")]
+fn when_all_campers_on_bus(game: &mut MockGame) {
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+    for _i in 0..max_ticks {
+        if game.get_all::<CamperInformation>().is_empty() {
+            return;
+        }
+
+        game.tick();
+    }
+
+    panic!("when_all_campers_on_bus: Campers never made it back to the bus.");
+}
+
+#[when("the bus reaches the exit,")]
+fn when_bus_reaches_exit(game: &mut MockGame) {
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let max_ticks = map_size.get_width() * map_size.get_height();
+    for _i in 0..max_ticks {
+        game.tick();
+
+        let bus_still_driving = !game.get_all_containing::<Pathfinding, Bus>().is_empty();
+        if !bus_still_driving {
+            return;
+        }
+    }
+
+    panic!("when_bus_reaches_exit: The bus never reached the exit.");
+}
+
 #[then(regex = r"the size of the map should be (\d+) by (\d+) by (\d+).")]
 fn verify_size_of_map(
     game: &mut MockGame,
@@ -106,6 +245,39 @@ fn verify_size_of_map(
     assert_eq!(expected_map_size, actual_map_size);
 }
 
+#[then(regex = r"there should be (\d+) locations of interest.")]
+fn verify_number_of_locations_of_interest(game: &mut MockGame, expected_num_locations: usize) {
+    let num_locations = game.get_all::<OutsideOfBus>().len()
+        + game.get_all::<MeadowEntrance>().len()
+        + game.get_all::<BusExit>().len();
+    assert_eq!(num_locations, expected_num_locations);
+}
+
+#[then("every spot in the map should have its own number.")]
+fn verify_spots_numbered_uniquely(game: &mut MockGame) {
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let num_spots = map_size.get_width() * map_size.get_height();
+
+    let mut spot_numbers = HashSet::new();
+    for y in 0..map_size.get_height() {
+        for x in 0..map_size.get_width() {
+            let spot_number = LogicalCoordinates::new(x, y, 0).to_1d(&map_size);
+            assert!(
+                spot_number < num_spots,
+                "verify_spots_numbered_uniquely: {}, {} is numbered past the end of the map.",
+                x,
+                y
+            );
+            assert!(
+                spot_numbers.insert(spot_number),
+                "verify_spots_numbered_uniquely: {}, {} shares its number with another spot.",
+                x,
+                y
+            );
+        }
+    }
+}
+
 #[then(regex = r"the hunger bar should be at (\d+)%.")]
 fn verify_hunger_bar_current_percentage(game: &mut MockGame, expected_current_percentage: usize) {
     let hunger_bar = game.get_resource::<HungerBar>();
@@ -311,6 +483,78 @@ fn verify_player_heading_to_meadows(game: &mut MockGame, expected_player_name: S
     assert_eq!(meadow_location, player_target_location);
 }
 
+#[then(regex = r"there should be (\d+) campers? present.")]
+fn verify_number_of_campers(game: &mut MockGame, expected_num_campers: usize) {
+    let num_campers = game
+        .get_all_containing::<Visibility, CamperInformation>()
+        .iter()
+        .filter(|camper| *camper.0 != Visibility::Hidden)
+        .count();
+    assert_eq!(num_campers, expected_num_campers);
+}
+
+#[then(regex = r"the camper for '(.+)' should appear outside of the meadows.")]
+fn verify_player_outside_of_meadows(game: &mut MockGame, expected_player_name: String) {
+    let all_campers_found = game.get_all_containing::<LogicalCoordinates, CamperInformation>();
+    let camper_location = *all_campers_found
+        .iter()
+        .find(|location_info| *location_info.1.get_camper_name() == expected_player_name)
+        .expect("verify_player_outside_of_meadows: Could not find camper.")
+        .0;
+    let meadow_location = *game.get_with::<LogicalCoordinates, MeadowEntrance>();
+    assert_eq!(camper_location, meadow_location);
+}
+
+#[then(regex = r"the camper for '(.+)' should be heading back to the bus.")]
+fn verify_player_heading_to_bus(game: &mut MockGame, expected_player_name: String) {
+    let outside_bus_location = *game.get_with::<LogicalCoordinates, OutsideOfBus>();
+
+    let camper_journey = *game
+        .get_all_containing::<CamperJourney, CamperInformation>()
+        .iter()
+        .find(|camper| camper.1.get_camper_name() == &expected_player_name)
+        .expect("verify_player_heading_to_bus: Could not find camper.")
+        .0;
+    assert_eq!(camper_journey, CamperJourney::HeadingToBus);
+
+    let camper_found = game.get_all_containing::<Pathfinding, CamperInformation>();
+    let player_target_location = *camper_found
+        .iter()
+        .find(|camper| camper.1.get_camper_name() == &expected_player_name)
+        .expect("verify_player_heading_to_bus: Could not find camper's path.")
+        .0
+        .get_destination();
+    assert_eq!(outside_bus_location, player_target_location);
+}
+
+#[then("the bus should be heading to the exit.")]
+fn verify_bus_heading_to_exit(game: &mut MockGame) {
+    let bus_exit_location = *game.get_with::<LogicalCoordinates, BusExit>();
+
+    let bus_found = game.get_all_containing::<Pathfinding, Bus>();
+    let bus_target_location = *bus_found
+        .first()
+        .expect("verify_bus_heading_to_exit: The bus is not going anywhere.")
+        .0
+        .get_destination();
+    assert_eq!(bus_exit_location, bus_target_location);
+}
+
+#[then(regex = r"there should be 1 bus made of (\d+) tiles?.")]
+fn verify_bus_tiles(game: &mut MockGame, expected_num_tiles: usize) {
+    let buses_found = game.get_all_containing::<Children, Bus>();
+    assert_eq!(buses_found.len(), 1);
+
+    let (bus_tiles, _) = buses_found[0];
+    assert_eq!(bus_tiles.len(), expected_num_tiles);
+}
+
+#[then("the campsite should be cleared.")]
+fn verify_campsite_cleared(game: &mut MockGame) {
+    let campsite_cleared = game.get_messages::<CampsiteCleared>();
+    assert_eq!(campsite_cleared.len(), 1);
+}
+
 fn main() {
     let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     feature_path.push("tests/features/surviving-the-trip-mvp.feature");
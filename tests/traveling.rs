@@ -0,0 +1,348 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use cucumber::{World, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    setting::{LogicalCoordinates, WorldTileDimensions},
+    traveling::{
+        DEFAULT_MOVEMENT_COST, Graph, MeadowEntrance, Movement, OutsideOfBus, Pathfinding,
+        WalkableTile,
+    },
+};
+
+/// Returns the path campers take from outside of the bus to the meadows.
+fn get_path_to_meadows(game: &mut MockGame) -> Pathfinding {
+    let outside_bus_location = *game.get_with::<LogicalCoordinates, OutsideOfBus>();
+    let meadows_location = *game.get_with::<LogicalCoordinates, MeadowEntrance>();
+
+    let traversal_graph = game.get_one::<Graph>();
+    Pathfinding::cheapest_path(
+        &outside_bus_location,
+        &meadows_location,
+        traversal_graph,
+        Movement::Straight,
+    )
+    .expect("get_path_to_meadows: Could not find a path to the meadows.")
+}
+
+/// Returns the spot written as "x, y" in a step.
+fn parse_spot(spot: &str) -> (usize, usize) {
+    let (x, y) = spot
+        .split_once(", ")
+        .expect("parse_spot: Spot is not written as x, y.");
+
+    (
+        x.parse().expect("parse_spot: x is not a number."),
+        y.parse().expect("parse_spot: y is not a number."),
+    )
+}
+
+/// Returns a path between two spots on the rendered map, found by the
+/// kind of pathfinding named, or None if there is no way to get there.
+fn find_path(
+    game: &mut MockGame,
+    path_kind: &str,
+    source: (usize, usize),
+    destination: (usize, usize),
+) -> Option<Pathfinding> {
+    let source = LogicalCoordinates::new(source.0, source.1, 0);
+    let destination = LogicalCoordinates::new(destination.0, destination.1, 0);
+    let traversal_graph = game.get_one::<Graph>();
+
+    match path_kind {
+        "shortest" => Pathfinding::shortest_path(&source, &destination, traversal_graph),
+        "cheapest" => {
+            Pathfinding::cheapest_path(&source, &destination, traversal_graph, Movement::Straight)
+        }
+        "cheapest diagonal" | "diagonal" => {
+            Pathfinding::cheapest_path(&source, &destination, traversal_graph, Movement::Diagonal)
+        }
+        _ => unreachable!("find_path: Unknown kind of path {}.", path_kind),
+    }
+}
+
+/// Returns the route described in a step, which is either exploring
+/// everything, or a patrol of some number of steps, or None if the route
+/// starts somewhere that cannot be walked on.
+fn get_route(game: &mut MockGame, route_kind: &str, source: &str) -> Option<Pathfinding> {
+    let (x, y) = parse_spot(source);
+    let source = LogicalCoordinates::new(x, y, 0);
+    let traversal_graph = game.get_one::<Graph>();
+
+    if route_kind == "exploring" {
+        return Pathfinding::explore_all(&source, traversal_graph);
+    }
+
+    let max_steps = route_kind
+        .trim_start_matches("a patrol of ")
+        .trim_end_matches(" steps")
+        .parse()
+        .expect("get_route: Unknown kind of route.");
+    Pathfinding::patrol(&source, traversal_graph, max_steps)
+}
+
+/// Returns how many steps it takes to follow a route.
+fn get_num_steps(route: &Pathfinding) -> usize {
+    route.get_locations().len() - 1
+}
+
+#[when(regex = r"the campsite map '(.+)' is rendered,")]
+fn when_campsite_map_rendered(game: &mut MockGame, map_name: String) {
+    game.render_map(&map_name);
+}
+
+#[then("every spot in the map should be walkable.")]
+fn verify_every_spot_walkable(game: &mut MockGame) {
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let traversal_graph = game.get_one::<Graph>();
+
+    for y in 0..map_size.get_height() {
+        for x in 0..map_size.get_width() {
+            let spot = LogicalCoordinates::new(x, y, 0);
+            assert!(
+                traversal_graph.has_node_at(&spot),
+                "verify_every_spot_walkable: {}, {} cannot be walked on.",
+                x,
+                y
+            );
+        }
+    }
+}
+
+#[then(regex = r"the spot at (\d+), (\d+) should be walkable.")]
+fn verify_spot_walkable(game: &mut MockGame, x: usize, y: usize) {
+    let spot = LogicalCoordinates::new(x, y, 0);
+    assert!(game.get_one::<Graph>().has_node_at(&spot));
+}
+
+#[then(regex = r"the spot at (\d+), (\d+) should not be walkable.")]
+fn verify_spot_not_walkable(game: &mut MockGame, x: usize, y: usize) {
+    let spot = LogicalCoordinates::new(x, y, 0);
+    assert!(!game.get_one::<Graph>().has_node_at(&spot));
+}
+
+#[then(regex = r"stepping onto the spot at (\d+), (\d+) should cost (\d+).")]
+fn verify_spot_cost(game: &mut MockGame, x: usize, y: usize, expected_cost: usize) {
+    let spot = LogicalCoordinates::new(x, y, 0);
+    let spot_node = game.get_one::<Graph>().get_node_at(&spot);
+
+    assert_eq!(spot_node.get_cost(), expected_cost);
+}
+
+#[then(
+    regex = r"the path from outside of the bus to the meadows should go through the spot at (\d+), (\d+)."
+)]
+fn verify_path_goes_through(game: &mut MockGame, x: usize, y: usize) {
+    let spot = LogicalCoordinates::new(x, y, 0);
+    let path_to_meadows = get_path_to_meadows(game);
+
+    assert!(path_to_meadows.get_locations().contains(&spot));
+}
+
+#[then("the path from outside of the bus to the meadows should only go through walkable spots.")]
+fn verify_path_walkable(game: &mut MockGame) {
+    let path_to_meadows = get_path_to_meadows(game);
+    let traversal_graph = game.get_one::<Graph>();
+
+    for path_location in path_to_meadows.get_locations() {
+        assert!(traversal_graph.has_node_at(&path_location));
+    }
+}
+
+#[then(
+    regex = r"the (shortest|cheapest|cheapest diagonal) path from (\d+, \d+) to (\d+, \d+) should go through the spot at (\d+), (\d+)."
+)]
+fn verify_found_path_goes_through(
+    game: &mut MockGame,
+    path_kind: String,
+    source: String,
+    destination: String,
+    x: usize,
+    y: usize,
+) {
+    let path = find_path(
+        game,
+        &path_kind,
+        parse_spot(&source),
+        parse_spot(&destination),
+    )
+    .expect("verify_found_path_goes_through: Could not find a path.");
+
+    assert!(
+        path.get_locations()
+            .contains(&LogicalCoordinates::new(x, y, 0))
+    );
+}
+
+#[then(
+    regex = r"the (shortest|cheapest|cheapest diagonal) path from (\d+, \d+) to (\d+, \d+) should not go through the spot at (\d+), (\d+)."
+)]
+fn verify_found_path_avoids(
+    game: &mut MockGame,
+    path_kind: String,
+    source: String,
+    destination: String,
+    x: usize,
+    y: usize,
+) {
+    let path = find_path(
+        game,
+        &path_kind,
+        parse_spot(&source),
+        parse_spot(&destination),
+    )
+    .expect("verify_found_path_avoids: Could not find a path.");
+
+    assert!(
+        !path
+            .get_locations()
+            .contains(&LogicalCoordinates::new(x, y, 0))
+    );
+}
+
+#[then(
+    regex = r"the (shortest|cheapest|cheapest diagonal) path from (\d+, \d+) to (\d+, \d+) should go through (\d+) spots."
+)]
+fn verify_found_path_length(
+    game: &mut MockGame,
+    path_kind: String,
+    source: String,
+    destination: String,
+    expected_num_spots: usize,
+) {
+    let path = find_path(
+        game,
+        &path_kind,
+        parse_spot(&source),
+        parse_spot(&destination),
+    )
+    .expect("verify_found_path_length: Could not find a path.");
+
+    assert_eq!(path.get_locations().len(), expected_num_spots);
+}
+
+#[then(regex = r"there should be no (\w+) path from (\d+, \d+) to (\d+, \d+).")]
+fn verify_no_path(game: &mut MockGame, path_kind: String, source: String, destination: String) {
+    let path = find_path(
+        game,
+        &path_kind,
+        parse_spot(&source),
+        parse_spot(&destination),
+    );
+
+    assert!(path.is_none());
+}
+
+#[then(regex = r"there should be no route (exploring|a patrol of \d+ steps) from (\d+, \d+).")]
+fn verify_no_route(game: &mut MockGame, route_kind: String, source: String) {
+    let route = get_route(game, &route_kind, &source);
+
+    assert!(route.is_none());
+}
+
+#[then(
+    regex = r"(exploring|a patrol of \d+ steps) from (\d+, \d+) should visit every walkable spot."
+)]
+fn verify_route_visits_all(game: &mut MockGame, route_kind: String, source: String) {
+    let route = get_route(game, &route_kind, &source)
+        .expect("verify_route_visits_all: Could not find a route.");
+    let visited_spots: HashSet<LogicalCoordinates> = route.get_locations().into_iter().collect();
+
+    let map_size = *game.get_one::<WorldTileDimensions>();
+    let traversal_graph = game.get_one::<Graph>();
+    for y in 0..map_size.get_height() {
+        for x in 0..map_size.get_width() {
+            let spot = LogicalCoordinates::new(x, y, 0);
+            assert_eq!(
+                visited_spots.contains(&spot),
+                traversal_graph.has_node_at(&spot),
+                "verify_route_visits_all: {}, {} was not visited as expected.",
+                x,
+                y
+            );
+        }
+    }
+}
+
+#[then(
+    regex = r"(exploring|a patrol of \d+ steps) from (\d+, \d+) should only ever step to a spot next to the last one."
+)]
+fn verify_route_steps_next_to(game: &mut MockGame, route_kind: String, source: String) {
+    let route_locations = get_route(game, &route_kind, &source)
+        .expect("verify_route_steps_next_to: Could not find a route.")
+        .get_locations();
+
+    for (last_spot, next_spot) in route_locations.iter().zip(route_locations.iter().skip(1)) {
+        let x_distance = last_spot.get_x().abs_diff(next_spot.get_x());
+        let y_distance = last_spot.get_y().abs_diff(next_spot.get_y());
+        assert_eq!(x_distance + y_distance, 1);
+    }
+}
+
+#[then(regex = r"(exploring|a patrol of \d+ steps) from (\d+, \d+) should take (\d+) steps.")]
+fn verify_route_num_steps(
+    game: &mut MockGame,
+    route_kind: String,
+    source: String,
+    expected_num_steps: usize,
+) {
+    let route = get_route(game, &route_kind, &source)
+        .expect("verify_route_num_steps: Could not find a route.");
+    assert_eq!(get_num_steps(&route), expected_num_steps);
+}
+
+#[then(
+    regex = r"(exploring|a patrol of \d+ steps) from (\d+, \d+) should take fewer than (\d+) steps."
+)]
+fn verify_route_max_steps(
+    game: &mut MockGame,
+    route_kind: String,
+    source: String,
+    max_steps: usize,
+) {
+    let route = get_route(game, &route_kind, &source)
+        .expect("verify_route_max_steps: Could not find a route.");
+    assert!(get_num_steps(&route) < max_steps);
+}
+
+#[then(
+    regex = r"(exploring|a patrol of \d+ steps) from (\d+, \d+) should visit (\d+) different spots."
+)]
+fn verify_route_num_spots(
+    game: &mut MockGame,
+    route_kind: String,
+    source: String,
+    expected_num_spots: usize,
+) {
+    let route = get_route(game, &route_kind, &source)
+        .expect("verify_route_num_spots: Could not find a route.");
+    let visited_spots: HashSet<LogicalCoordinates> = route.get_locations().into_iter().collect();
+
+    assert_eq!(visited_spots.len(), expected_num_spots);
+}
+
+#[then(regex = r"exploring an open world of (\d+) by (\d+) tiles should visit every spot.")]
+fn verify_open_world_explored(_game: &mut MockGame, width: usize, height: usize) {
+    let world_size = WorldTileDimensions::new(width, height, 1);
+    let walkable_tiles: Vec<WalkableTile> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| LogicalCoordinates::new(x, y, 0)))
+        .map(|location| WalkableTile::new(location, DEFAULT_MOVEMENT_COST))
+        .collect();
+    let open_world = Graph::from_walkable_tiles(&walkable_tiles, &world_size);
+
+    let route = Pathfinding::explore_all(&LogicalCoordinates::new(0, 0, 0), &open_world)
+        .expect("verify_open_world_explored: Could not explore the open world.");
+    let visited_spots: HashSet<LogicalCoordinates> = route.get_locations().into_iter().collect();
+
+    assert_eq!(visited_spots.len(), width * height);
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/traveling.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}
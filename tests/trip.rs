@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use cucumber::{World, given, then, when};
+
+mod mock_game;
+use mock_game::*;
+
+use surviving_the_trip::core_logic::{
+    interacting::{ScenarioAttempt, ScenarioResult, ViewerIdentity},
+    progressing::{CamperObjective, CampsiteCleared, Trip, TripProgress},
+};
+
+#[given(regex = r"a trip called '(.+)',")]
+fn given_trip_file(game: &mut MockGame, trip_name: String) {
+    game.trip_name = trip_name;
+}
+
+#[when("the trip begins,")]
+fn when_trip_begins(game: &mut MockGame) {
+    let trip_name = game.trip_name.clone();
+    game.begin_trip(&trip_name);
+}
+
+#[when(regex = r"'(.+)' succeeds the (\d+)[a-z]+ objective,")]
+fn when_player_succeeds_objective(game: &mut MockGame, player_name: String, objective_num: usize) {
+    let camper_objective = game.get_all::<CamperObjective>()[objective_num - 1];
+    let objective_name = camper_objective.get_name();
+
+    let player = ViewerIdentity::new(player_name);
+    let scenario_attempt = ScenarioAttempt::new(player, objective_name, ScenarioResult::Success);
+    game.broadcast(scenario_attempt);
+}
+
+#[when("the campsite is cleared,")]
+fn when_campsite_cleared(game: &mut MockGame) {
+    game.broadcast(CampsiteCleared);
+    // One tick to unload the cleared campsite,
+    game.tick();
+    // And another to load the next one.
+    game.tick();
+}
+
+#[then(regex = r"the current campsite should be '(.+)'.")]
+fn verify_current_campsite(game: &mut MockGame, expected_campsite_name: String) {
+    let current_campsite_name = game
+        .get_resource::<TripProgress>()
+        .get_current_campsite()
+        .expect("verify_current_campsite: The campers are not at any campsite.")
+        .get_name();
+    assert_eq!(current_campsite_name, expected_campsite_name);
+}
+
+#[then(regex = r"there should be (\d+) objectives.")]
+fn verify_number_of_objectives(game: &mut MockGame, expected_num_objectives: usize) {
+    let num_objectives = game.get_all::<CamperObjective>().len();
+    assert_eq!(num_objectives, expected_num_objectives);
+}
+
+#[then(regex = r"(\d+) campsites should have been survived.")]
+fn verify_campsites_survived(game: &mut MockGame, expected_campsites_survived: usize) {
+    let campsites_survived = game.get_resource::<TripProgress>().get_campsites_survived();
+    assert_eq!(campsites_survived, expected_campsites_survived);
+}
+
+#[then(regex = r"there should be (\d+) total contributions.")]
+fn verify_total_contributions(game: &mut MockGame, expected_total_contributions: usize) {
+    let total_contributions = game
+        .get_resource::<TripProgress>()
+        .get_total_contributions();
+    assert_eq!(total_contributions, expected_total_contributions);
+}
+
+#[then("the trip should be finished.")]
+fn verify_trip_finished(game: &mut MockGame) {
+    assert!(game.get_resource::<TripProgress>().is_finished());
+}
+
+#[then(regex = r"the trip should fail to load at '(.+)'.")]
+fn verify_trip_load_failed(game: &mut MockGame, expected_json_path: String) {
+    let mut trip_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    trip_path.push("tests/assets/trips/");
+    trip_path.push(&game.trip_name);
+
+    let trip_error = Trip::from_file(&trip_path)
+        .expect_err("verify_trip_load_failed: The trip loaded without problems.");
+    assert_eq!(&expected_json_path, trip_error.get_json_path());
+}
+
+fn main() {
+    let mut feature_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    feature_path.push("tests/features/trip.feature");
+
+    futures::executor::block_on(MockGame::run(feature_path));
+}